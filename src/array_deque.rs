@@ -0,0 +1,382 @@
+use core::iter::*;
+use core::mem::{ManuallyDrop, MaybeUninit};
+use crate::utils::*;
+use crate::array_vec::CapacityError;
+use crate::into_iter::{self, IntoIter};
+
+
+/// A double-ended queue with a fixed capacity of `N`, stored inline as a ring buffer.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use array_helpers::*;
+/// let mut q = ArrayDeque::<u32, 3>::new();
+/// q.push_back(2);
+/// q.push_back(3);
+/// q.push_front(1);
+/// assert_eq!(q.pop_front(), Some(1));
+/// assert_eq!(q.pop_back(), Some(3));
+/// assert_eq!(q.len(), 1);
+/// ```
+///
+/// Keeping the most recent samples:
+///
+/// ```
+/// # use array_helpers::*;
+/// let mut recent = ArrayDeque::<u32, 3>::new();
+/// for sample in 1..=5 {
+///     recent.force_push_back(sample);
+/// }
+/// assert_eq!(recent.into_inner().ok(), Some([3, 4, 5]));
+/// ```
+pub struct ArrayDeque<T, const N: usize> {
+	/// The `len` elements starting at index `head`, wrapping around the end of the array, are initialized.
+	/// Everything else is uninitialized.
+	arr: [MaybeUninit<T>; N],
+	/// The index of the front element.
+	head: usize,
+	len: usize,
+}
+
+impl<T, const N: usize> ArrayDeque<T,N> {
+	/// Creates an empty `ArrayDeque`.
+	pub fn new() -> Self {
+		ArrayDeque {
+			arr: uninit_array(),
+			head: 0,
+			len: 0,
+		}
+	}
+
+	/// The number of elements.
+	pub fn len(&self) -> usize {
+		self.len
+	}
+
+	/// Returns `true` if there are no elements.
+	pub fn is_empty(&self) -> bool {
+		self.len == 0
+	}
+
+	/// Returns `true` if there is no room for another element.
+	pub fn is_full(&self) -> bool {
+		self.len == N
+	}
+
+	/// The maximum number of elements, `N`.
+	pub fn capacity(&self) -> usize {
+		N
+	}
+
+	/// The index in `arr` of the element at position `i`. Must only be called if `N > 0`.
+	fn physical(&self, i: usize) -> usize {
+		(self.head + i) % N
+	}
+
+	/// Appends an element to the back, or hands it back if the `ArrayDeque` is full.
+	pub fn try_push_back(&mut self, item: T) -> Result<(), CapacityError<T>> {
+		if self.len == N {
+			return Err(CapacityError(item));
+		}
+		let i = self.physical(self.len);
+		self.arr[i] = MaybeUninit::new(item);
+		self.len += 1;
+		Ok(())
+	}
+
+	/// Prepends an element to the front, or hands it back if the `ArrayDeque` is full.
+	pub fn try_push_front(&mut self, item: T) -> Result<(), CapacityError<T>> {
+		if self.len == N {
+			return Err(CapacityError(item));
+		}
+		self.head = self.physical(N - 1);
+		self.arr[self.head] = MaybeUninit::new(item);
+		self.len += 1;
+		Ok(())
+	}
+
+	/// Appends an element to the back.
+	///
+	/// Panics if the `ArrayDeque` is full.
+	pub fn push_back(&mut self, item: T) {
+		if self.try_push_back(item).is_err() {
+			panic!("ArrayDeque::push_back called on a full ArrayDeque of capacity {}", N);
+		}
+	}
+
+	/// Prepends an element to the front.
+	///
+	/// Panics if the `ArrayDeque` is full.
+	pub fn push_front(&mut self, item: T) {
+		if self.try_push_front(item).is_err() {
+			panic!("ArrayDeque::push_front called on a full ArrayDeque of capacity {}", N);
+		}
+	}
+
+	/// Appends an element to the back. If the `ArrayDeque` is full, the front element is removed to make room, and returned.
+	///
+	/// If the capacity is zero, the new element is returned instead.
+	pub fn force_push_back(&mut self, item: T) -> Option<T> {
+		if N == 0 {
+			return Some(item);
+		}
+		let evicted = if self.len == N { self.pop_front() } else { None };
+		self.push_back(item);
+		evicted
+	}
+
+	/// Prepends an element to the front. If the `ArrayDeque` is full, the back element is removed to make room, and returned.
+	///
+	/// If the capacity is zero, the new element is returned instead.
+	pub fn force_push_front(&mut self, item: T) -> Option<T> {
+		if N == 0 {
+			return Some(item);
+		}
+		let evicted = if self.len == N { self.pop_back() } else { None };
+		self.push_front(item);
+		evicted
+	}
+
+	/// Removes the front element and returns it, or `None` if the `ArrayDeque` is empty.
+	pub fn pop_front(&mut self) -> Option<T> {
+		if self.len == 0 {
+			return None;
+		}
+		let i = self.head;
+		self.head = self.physical(1);
+		self.len -= 1;
+		Some(unsafe { self.arr[i].assume_init_read() })
+	}
+
+	/// Removes the back element and returns it, or `None` if the `ArrayDeque` is empty.
+	pub fn pop_back(&mut self) -> Option<T> {
+		if self.len == 0 {
+			return None;
+		}
+		self.len -= 1;
+		let i = self.physical(self.len);
+		Some(unsafe { self.arr[i].assume_init_read() })
+	}
+
+	/// The element at position `i`, counting from the front.
+	pub fn get(&self, i: usize) -> Option<&T> {
+		if i < self.len {
+			Some(unsafe { self.arr[self.physical(i)].assume_init_ref() })
+		} else {
+			None
+		}
+	}
+
+	/// The element at position `i`, counting from the front.
+	pub fn get_mut(&mut self, i: usize) -> Option<&mut T> {
+		if i < self.len {
+			let i = self.physical(i);
+			Some(unsafe { self.arr[i].assume_init_mut() })
+		} else {
+			None
+		}
+	}
+
+	/// The front element.
+	pub fn front(&self) -> Option<&T> {
+		self.get(0)
+	}
+
+	/// The back element.
+	pub fn back(&self) -> Option<&T> {
+		match self.len {
+			0 => None,
+			len => self.get(len - 1),
+		}
+	}
+
+	/// The elements, in order, as two slices.
+	///
+	/// The second slice is empty unless the elements wrap around the end of the buffer.
+	///
+	/// # Example
+	///
+	/// Basic usage:
+	///
+	/// ```
+	/// # use array_helpers::*;
+	/// let mut q = ArrayDeque::<u32, 4>::new();
+	/// q.push_back(2);
+	/// q.push_back(3);
+	/// q.push_front(1);
+	/// assert_eq!(q.as_slices(), (&[1][..], &[2, 3][..]));
+	/// ```
+	pub fn as_slices(&self) -> (&[T], &[T]) {
+		let (front, back) = self.ranges();
+		unsafe { (assume_init_ref(&self.arr[front]), assume_init_ref(&self.arr[back])) }
+	}
+
+	/// The elements, in order, as two mutable slices.
+	///
+	/// The second slice is empty unless the elements wrap around the end of the buffer.
+	pub fn as_mut_slices(&mut self) -> (&mut [T], &mut [T]) {
+		let (front, back) = self.ranges();
+		let (rest, first) = self.arr.split_at_mut(front.start);
+		unsafe { (assume_init_mut(&mut first[..front.len()]), assume_init_mut(&mut rest[back])) }
+	}
+
+	/// The indices in `arr` of the elements, in order.
+	fn ranges(&self) -> (core::ops::Range<usize>, core::ops::Range<usize>) {
+		let end = self.head + self.len;
+		if end <= N {
+			(self.head..end, 0..0)
+		} else {
+			(self.head..N, 0..end - N)
+		}
+	}
+
+	/// Iterates over the elements, from front to back.
+	pub fn iter(&self) -> Chain<core::slice::Iter<'_,T>, core::slice::Iter<'_,T>> {
+		let (front, back) = self.as_slices();
+		front.iter().chain(back)
+	}
+
+	/// Iterates mutably over the elements, from front to back.
+	pub fn iter_mut(&mut self) -> Chain<core::slice::IterMut<'_,T>, core::slice::IterMut<'_,T>> {
+		let (front, back) = self.as_mut_slices();
+		front.iter_mut().chain(back)
+	}
+
+	/// Removes every element.
+	pub fn clear(&mut self) {
+		let (front, back) = self.as_mut_slices();
+		let (front, back) = (front as *mut [T], back as *mut [T]);
+		// Empty the deque first, so that a panicking destructor cannot cause a double drop.
+		self.head = 0;
+		self.len = 0;
+		// If dropping the front panics, this still drops the back.
+		let _back = DropInPlace(back);
+		unsafe { core::ptr::drop_in_place(front) }
+	}
+
+	/// Moves the elements to the start of the buffer, in order.
+	///
+	/// This rotates the buffer in place, rather than copying it through a temporary.
+	fn make_contiguous(&mut self) {
+		self.arr.rotate_left(self.head);
+		self.head = 0;
+	}
+
+	/// Converts the `ArrayDeque` into an array, from front to back, if it is full.
+	///
+	/// Otherwise, the `ArrayDeque` is handed back unchanged.
+	pub fn into_inner(mut self) -> Result<[T;N], Self> {
+		if self.len < N {
+			return Err(self);
+		}
+		self.make_contiguous();
+		let this = ManuallyDrop::new(self);
+		Ok(unsafe { assume_init_array(core::ptr::read(&this.arr)) })
+	}
+}
+
+/// Drops the elements of a slice when it goes out of scope.
+struct DropInPlace<T>(*mut [T]);
+
+impl<T> Drop for DropInPlace<T> {
+	fn drop(&mut self) {
+		unsafe { core::ptr::drop_in_place(self.0) }
+	}
+}
+
+impl<T, const N: usize> Drop for ArrayDeque<T,N> {
+	fn drop(&mut self) {
+		self.clear()
+	}
+}
+
+impl<T, const N: usize> Default for ArrayDeque<T,N> {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl<T, const N: usize> From<[T;N]> for ArrayDeque<T,N> {
+	/// Creates a full `ArrayDeque`, whose front is the first element of the array.
+	fn from(arr: [T;N]) -> Self {
+		ArrayDeque {
+			arr: push_maybe_uninit(MaybeUninit::new(arr)),
+			head: 0,
+			len: N,
+		}
+	}
+}
+
+impl<T, const N: usize> Extend<T> for ArrayDeque<T,N> {
+	/// Appends each item to the back. Panics if the items do not fit.
+	fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+		for item in iter {
+			self.push_back(item);
+		}
+	}
+}
+
+impl<T, const N: usize> FromIterator<T> for ArrayDeque<T,N> {
+	/// Panics if the iterator has more than `N` items.
+	fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+		let mut out = Self::new();
+		out.extend(iter);
+		out
+	}
+}
+
+impl<T, const N: usize> IntoIterator for ArrayDeque<T,N> {
+	type Item = T;
+	type IntoIter = IntoIter<T,N>;
+	/// Iterates over the elements by value, from front to back.
+	fn into_iter(mut self) -> IntoIter<T,N> {
+		self.make_contiguous();
+		let this = ManuallyDrop::new(self);
+		unsafe { into_iter::from_raw_parts(core::ptr::read(&this.arr), 0..this.len) }
+	}
+}
+
+impl<'a, T, const N: usize> IntoIterator for &'a ArrayDeque<T,N> {
+	type Item = &'a T;
+	type IntoIter = Chain<core::slice::Iter<'a,T>, core::slice::Iter<'a,T>>;
+	fn into_iter(self) -> Self::IntoIter {
+		self.iter()
+	}
+}
+
+impl<T: Clone, const N: usize> Clone for ArrayDeque<T,N> {
+	fn clone(&self) -> Self {
+		// If a `clone` panics, `out` drops the clones made so far.
+		let mut out = Self::new();
+		for item in self.iter() {
+			out.push_back(item.clone());
+		}
+		out
+	}
+}
+
+impl<T: PartialEq, const N: usize> PartialEq for ArrayDeque<T,N> {
+	fn eq(&self, other: &Self) -> bool {
+		self.iter().eq(other.iter())
+	}
+}
+
+impl<T: Eq, const N: usize> Eq for ArrayDeque<T,N> {}
+
+impl<T: core::hash::Hash, const N: usize> core::hash::Hash for ArrayDeque<T,N> {
+	fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+		state.write_usize(self.len);
+		for item in self.iter() {
+			item.hash(state);
+		}
+	}
+}
+
+impl<T: core::fmt::Debug, const N: usize> core::fmt::Debug for ArrayDeque<T,N> {
+	fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+		f.debug_list().entries(self.iter()).finish()
+	}
+}
@@ -0,0 +1,480 @@
+use core::iter::*;
+use core::mem::{ManuallyDrop, MaybeUninit};
+use core::ops::{Bound, Range, RangeBounds};
+use crate::utils::*;
+use crate::into_iter::{self, IntoIter};
+
+
+/// A vector with a fixed capacity of `N`, stored inline.
+///
+/// It dereferences to a slice of its elements.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use array_helpers::*;
+/// let mut iter = Array::into_iter([1, 2, 3, 4]);
+/// iter.next();
+/// let rest: ArrayVec<usize, 4> = iter.into_array_vec();
+/// assert_eq!(*rest, [2, 3, 4]);
+/// assert_eq!(rest.capacity(), 4);
+/// ```
+///
+/// Building one up:
+///
+/// ```
+/// # use array_helpers::*;
+/// let mut v = ArrayVec::<char, 3>::new();
+/// v.push('a');
+/// v.push('c');
+/// v.insert(1, 'b');
+/// assert_eq!(*v, ['a', 'b', 'c']);
+/// assert!(v.try_push('d').is_err());
+/// assert_eq!(v.into_inner().ok(), Some(['a', 'b', 'c']));
+/// ```
+pub struct ArrayVec<T, const N: usize> {
+	/// arr[0..len] is initialized. arr[len..N] is uninitialized.
+	arr: [MaybeUninit<T>; N],
+	len: usize,
+}
+
+/// Create an ArrayVec from an array whose first `len` elements are initialized.
+pub unsafe fn from_raw_parts<T, const N: usize>(arr: [MaybeUninit<T>; N], len: usize) -> ArrayVec<T,N> {
+	ArrayVec { arr, len }
+}
+
+impl<T, const N: usize> ArrayVec<T,N> {
+	/// Creates an empty `ArrayVec`.
+	pub fn new() -> Self {
+		ArrayVec {
+			arr: uninit_array(),
+			len: 0,
+		}
+	}
+
+	/// The number of elements.
+	pub fn len(&self) -> usize {
+		self.len
+	}
+
+	/// Returns `true` if there are no elements.
+	pub fn is_empty(&self) -> bool {
+		self.len == 0
+	}
+
+	/// Returns `true` if there is no room for another element.
+	pub fn is_full(&self) -> bool {
+		self.len == N
+	}
+
+	/// The maximum number of elements, `N`.
+	pub fn capacity(&self) -> usize {
+		N
+	}
+
+	/// The elements, as a slice.
+	pub fn as_slice(&self) -> &[T] {
+		unsafe { core::slice::from_raw_parts(self.arr.as_ptr() as *const T, self.len) }
+	}
+
+	/// The elements, as a mutable slice.
+	pub fn as_mut_slice(&mut self) -> &mut [T] {
+		unsafe { core::slice::from_raw_parts_mut(self.arr.as_mut_ptr() as *mut T, self.len) }
+	}
+
+	/// Appends an element, or hands it back if the `ArrayVec` is full.
+	///
+	/// # Example
+	///
+	/// Basic usage:
+	///
+	/// ```
+	/// # use array_helpers::*;
+	/// let mut v = ArrayVec::<u8, 1>::new();
+	/// assert_eq!(v.try_push(1), Ok(()));
+	/// assert_eq!(v.try_push(2), Err(CapacityError(2)));
+	/// ```
+	pub fn try_push(&mut self, item: T) -> Result<(), CapacityError<T>> {
+		if self.len == N {
+			return Err(CapacityError(item));
+		}
+		self.arr[self.len] = MaybeUninit::new(item);
+		self.len += 1;
+		Ok(())
+	}
+
+	/// Appends an element.
+	///
+	/// Panics if the `ArrayVec` is full.
+	pub fn push(&mut self, item: T) {
+		if self.try_push(item).is_err() {
+			panic!("ArrayVec::push called on a full ArrayVec of capacity {}", N);
+		}
+	}
+
+	/// Removes the last element and returns it, or `None` if the `ArrayVec` is empty.
+	pub fn pop(&mut self) -> Option<T> {
+		if self.len == 0 {
+			return None;
+		}
+		self.len -= 1;
+		Some(unsafe { self.arr[self.len].assume_init_read() })
+	}
+
+	/// Inserts an element at position `index`, shifting the later elements to the right.
+	///
+	/// Panics if `index > len`, or if the `ArrayVec` is full.
+	pub fn insert(&mut self, index: usize, item: T) {
+		assert!(index <= self.len, "insertion index (is {}) should be <= len (is {})", index, self.len);
+		assert!(self.len < N, "ArrayVec::insert called on a full ArrayVec of capacity {}", N);
+		unsafe {
+			let ptr = self.arr.as_mut_ptr().add(index);
+			core::ptr::copy(ptr, ptr.add(1), self.len - index);
+			ptr.write(MaybeUninit::new(item));
+		}
+		self.len += 1;
+	}
+
+	/// Removes and returns the element at position `index`, shifting the later elements to the left.
+	///
+	/// Panics if `index >= len`.
+	pub fn remove(&mut self, index: usize) -> T {
+		assert!(index < self.len, "removal index (is {}) should be < len (is {})", index, self.len);
+		self.len -= 1;
+		unsafe {
+			let ptr = self.arr.as_mut_ptr().add(index);
+			let item = ptr.read().assume_init();
+			core::ptr::copy(ptr.add(1), ptr, self.len - index);
+			item
+		}
+	}
+
+	/// Shortens the `ArrayVec` to `len` elements, dropping the rest.
+	///
+	/// Does nothing if it is already no longer than `len`.
+	pub fn truncate(&mut self, len: usize) {
+		if len >= self.len {
+			return;
+		}
+		let tail = core::ptr::slice_from_raw_parts_mut(unsafe { self.arr.as_mut_ptr().add(len) } as *mut T, self.len - len);
+		// Shrink first, so that a panicking destructor cannot cause a double drop.
+		self.len = len;
+		unsafe { core::ptr::drop_in_place(tail) }
+	}
+
+	/// Removes every element.
+	pub fn clear(&mut self) {
+		self.truncate(0)
+	}
+
+	/// Removes the elements in `range`, and returns an iterator over them.
+	///
+	/// The later elements are shifted down when the iterator is dropped.
+	/// Any elements that the iterator did not yield are dropped with it.
+	///
+	/// Panics if the range is out of bounds.
+	///
+	/// # Example
+	///
+	/// Basic usage:
+	///
+	/// ```
+	/// # use array_helpers::*;
+	/// let mut v = ArrayVec::from([1, 2, 3, 4, 5]);
+	/// let middle: Vec<u32> = v.drain(1..3).collect();
+	/// assert_eq!(middle, [2, 3]);
+	/// assert_eq!(*v, [1, 4, 5]);
+	/// ```
+	pub fn drain<R: RangeBounds<usize>>(&mut self, range: R) -> Drain<'_,T,N> {
+		let start = match range.start_bound() {
+			Bound::Included(&i) => i,
+			Bound::Excluded(&i) => i.checked_add(1).expect("drain range start overflows usize"),
+			Bound::Unbounded => 0,
+		};
+		let end = match range.end_bound() {
+			Bound::Included(&i) => i.checked_add(1).expect("drain range end overflows usize"),
+			Bound::Excluded(&i) => i,
+			Bound::Unbounded => self.len,
+		};
+		assert!(start <= end, "drain range starts at {} but ends at {}", start, end);
+		assert!(end <= self.len, "drain range end (is {}) should be <= len (is {})", end, self.len);
+		let tail_len = self.len - end;
+		// Until the `Drain` is dropped, only the elements before the range belong to the `ArrayVec`.
+		self.len = start;
+		Drain {
+			vec: self,
+			alive: start..end,
+			tail_start: end,
+			tail_len,
+		}
+	}
+
+	/// Appends the items of an iterator, stopping at the first one that does not fit.
+	///
+	/// That item is handed back in the error. The rest of the iterator is left unconsumed.
+	///
+	/// # Example
+	///
+	/// Basic usage:
+	///
+	/// ```
+	/// # use array_helpers::*;
+	/// let mut v = ArrayVec::<u32, 3>::new();
+	/// let mut iter = 1..10;
+	/// assert_eq!(v.try_extend(&mut iter), Err(CapacityError(4)));
+	/// assert_eq!(*v, [1, 2, 3]);
+	/// assert_eq!(iter.next(), Some(5));
+	/// ```
+	pub fn try_extend<I: IntoIterator<Item = T>>(&mut self, iter: I) -> Result<(), CapacityError<T>> {
+		for item in iter {
+			self.try_push(item)?;
+		}
+		Ok(())
+	}
+
+	/// Converts the `ArrayVec` into an array, if it is full.
+	///
+	/// Otherwise, the `ArrayVec` is handed back unchanged.
+	pub fn into_inner(self) -> Result<[T;N], Self> {
+		if self.len < N {
+			return Err(self);
+		}
+		let this = ManuallyDrop::new(self);
+		Ok(unsafe { assume_init_array(core::ptr::read(&this.arr)) })
+	}
+}
+
+impl<T, const N: usize> Drop for ArrayVec<T,N> {
+	fn drop(&mut self) {
+		unsafe { core::ptr::drop_in_place(self.as_mut_slice()) }
+	}
+}
+
+impl<T, const N: usize> Default for ArrayVec<T,N> {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl<T, const N: usize> core::ops::Deref for ArrayVec<T,N> {
+	type Target = [T];
+	fn deref(&self) -> &[T] {
+		self.as_slice()
+	}
+}
+
+impl<T, const N: usize> core::ops::DerefMut for ArrayVec<T,N> {
+	fn deref_mut(&mut self) -> &mut [T] {
+		self.as_mut_slice()
+	}
+}
+
+impl<T, const N: usize> From<[T;N]> for ArrayVec<T,N> {
+	fn from(arr: [T;N]) -> Self {
+		ArrayVec {
+			arr: push_maybe_uninit(MaybeUninit::new(arr)),
+			len: N,
+		}
+	}
+}
+
+impl<T, const N: usize> Extend<T> for ArrayVec<T,N> {
+	/// Panics if the items do not fit. Use [`try_extend`](./struct.ArrayVec.html#method.try_extend) to handle that case.
+	fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+		if self.try_extend(iter).is_err() {
+			panic!("ArrayVec::extend overflowed an ArrayVec of capacity {}", N);
+		}
+	}
+}
+
+impl<T, const N: usize> FromIterator<T> for ArrayVec<T,N> {
+	/// Panics if the iterator has more than `N` items.
+	fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+		let mut out = Self::new();
+		out.extend(iter);
+		out
+	}
+}
+
+impl<T, const N: usize> IntoIterator for ArrayVec<T,N> {
+	type Item = T;
+	type IntoIter = IntoIter<T,N>;
+	fn into_iter(self) -> IntoIter<T,N> {
+		let this = ManuallyDrop::new(self);
+		unsafe { into_iter::from_raw_parts(core::ptr::read(&this.arr), 0..this.len) }
+	}
+}
+
+impl<'a, T, const N: usize> IntoIterator for &'a ArrayVec<T,N> {
+	type Item = &'a T;
+	type IntoIter = core::slice::Iter<'a, T>;
+	fn into_iter(self) -> Self::IntoIter {
+		self.as_slice().iter()
+	}
+}
+
+impl<'a, T, const N: usize> IntoIterator for &'a mut ArrayVec<T,N> {
+	type Item = &'a mut T;
+	type IntoIter = core::slice::IterMut<'a, T>;
+	fn into_iter(self) -> Self::IntoIter {
+		self.as_mut_slice().iter_mut()
+	}
+}
+
+impl<T: Clone, const N: usize> Clone for ArrayVec<T,N> {
+	fn clone(&self) -> Self {
+		// If a `clone` panics, `out` drops the clones made so far.
+		let mut out = Self::new();
+		for item in self.as_slice() {
+			out.push(item.clone());
+		}
+		out
+	}
+}
+
+impl<T: PartialEq, const N: usize> PartialEq for ArrayVec<T,N> {
+	fn eq(&self, other: &Self) -> bool {
+		self.as_slice().eq(other.as_slice())
+	}
+}
+
+impl<T: Eq, const N: usize> Eq for ArrayVec<T,N> {}
+
+impl<T: PartialOrd, const N: usize> PartialOrd for ArrayVec<T,N> {
+	fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+		self.as_slice().partial_cmp(other.as_slice())
+	}
+}
+
+impl<T: Ord, const N: usize> Ord for ArrayVec<T,N> {
+	fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+		self.as_slice().cmp(other.as_slice())
+	}
+}
+
+impl<T: core::hash::Hash, const N: usize> core::hash::Hash for ArrayVec<T,N> {
+	fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+		self.as_slice().hash(state)
+	}
+}
+
+impl<T: core::fmt::Debug, const N: usize> core::fmt::Debug for ArrayVec<T,N> {
+	fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+		self.as_slice().fmt(f)
+	}
+}
+
+
+/// A draining iterator for [`ArrayVec`].
+///
+/// This struct is created by the [`drain`] method.
+///
+/// [`ArrayVec`]: ./struct.ArrayVec.html
+/// [`drain`]: ./struct.ArrayVec.html#method.drain
+pub struct Drain<'a, T, const N: usize> {
+	/// The `ArrayVec`, whose length is temporarily cut off at the start of the drained range.
+	vec: &'a mut ArrayVec<T,N>,
+	/// The indices of the drained elements that have not yet been yielded.
+	alive: Range<usize>,
+	/// The position and length of the elements after the drained range.
+	tail_start: usize,
+	tail_len: usize,
+}
+
+impl<T, const N: usize> Drain<'_,T,N> {
+	/// Returns the elements that have not yet been yielded, as a slice.
+	pub fn as_slice(&self) -> &[T] {
+		unsafe {
+			let start = self.vec.arr.as_ptr().add(self.alive.start) as *const T;
+			core::slice::from_raw_parts(start, self.alive.len())
+		}
+	}
+}
+
+impl<T, const N: usize> Drop for Drain<'_,T,N> {
+	fn drop(&mut self) {
+		/// Moves the tail down, even if dropping one of the drained elements panics.
+		struct MoveTail<'b, 'a, T, const N: usize>(&'b mut Drain<'a,T,N>);
+
+		impl<T, const N: usize> Drop for MoveTail<'_,'_,T,N> {
+			fn drop(&mut self) {
+				let drain = &mut *self.0;
+				unsafe {
+					let ptr = drain.vec.arr.as_mut_ptr();
+					core::ptr::copy(ptr.add(drain.tail_start), ptr.add(drain.vec.len), drain.tail_len);
+				}
+				drain.vec.len += drain.tail_len;
+			}
+		}
+
+		let guard = MoveTail(self);
+		let drain = &mut *guard.0;
+		let remaining = core::ptr::slice_from_raw_parts_mut(unsafe { drain.vec.arr.as_mut_ptr().add(drain.alive.start) } as *mut T, drain.alive.len());
+		drain.alive.start = drain.alive.end;
+		unsafe { core::ptr::drop_in_place(remaining) }
+	}
+}
+
+impl<T, const N: usize> Iterator for Drain<'_,T,N> {
+	type Item = T;
+	fn next(&mut self) -> Option<T> {
+		self.alive.next().map(|i| unsafe { self.vec.arr[i].assume_init_read() })
+	}
+	// Implemented in terms of ExactSizeIterator.
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let out = self.len();
+		(out, Some(out))
+	}
+}
+
+impl<T, const N: usize> DoubleEndedIterator for Drain<'_,T,N> {
+	fn next_back(&mut self) -> Option<T> {
+		self.alive.next_back().map(|i| unsafe { self.vec.arr[i].assume_init_read() })
+	}
+}
+
+impl<T, const N: usize> ExactSizeIterator for Drain<'_,T,N> {
+	fn len(&self) -> usize {
+		self.alive.len()
+	}
+}
+impl<T, const N: usize> FusedIterator for Drain<'_,T,N> {}
+
+impl<T: core::fmt::Debug, const N: usize> core::fmt::Debug for Drain<'_,T,N> {
+	fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+		f.debug_tuple("Drain").field(&self.as_slice()).finish()
+	}
+}
+
+
+/// The error returned when an [`ArrayVec`] has no room for another element.
+///
+/// It holds the element that did not fit.
+///
+/// [`ArrayVec`]: ./struct.ArrayVec.html
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct CapacityError<T>(pub T);
+
+impl<T> CapacityError<T> {
+	/// Returns the element that did not fit.
+	pub fn into_inner(self) -> T {
+		self.0
+	}
+}
+
+impl<T> core::fmt::Debug for CapacityError<T> {
+	fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+		f.write_str("CapacityError { .. }")
+	}
+}
+
+impl<T> core::fmt::Display for CapacityError<T> {
+	fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+		f.write_str("insufficient capacity")
+	}
+}
+
+#[cfg(feature = "std")]
+impl<T> std::error::Error for CapacityError<T> {}
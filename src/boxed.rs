@@ -0,0 +1,274 @@
+use core::iter::*;
+use core::marker::PhantomData;
+use core::mem::{ManuallyDrop, MaybeUninit};
+use core::ops::Range;
+use core::ptr::NonNull;
+use alloc::alloc::{alloc_zeroed, handle_alloc_error, Layout};
+use alloc::boxed::Box;
+use alloc::collections::TryReserveError;
+use alloc::vec::Vec;
+use crate::utils::*;
+use crate::Zeroable;
+
+
+/// Boxed Array Iterator
+///
+/// This struct is created by the [`into_iter`] method on boxed arrays.
+/// The elements stay in their heap allocation until they are yielded.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use array_helpers::*;
+/// let arr: Box<[String; 3]> = new_boxed(|i| i.to_string());
+/// let v: Vec<String> = arr.into_iter().rev().collect();
+/// assert_eq!(v, ["2", "1", "0"]);
+/// ```
+///
+/// [`into_iter`]: ./trait.BoxedArray.html#tymethod.into_iter
+pub struct BoxedIntoIter<T, const N: usize> {
+	/// arr[alive] are the remaining elements in the iterator. Everything outside of that range is uninitialized.
+	arr: Box<[MaybeUninit<T>; N]>,
+	/// The indices of the elements that have not yet been yielded.
+	alive: Range<usize>,
+}
+
+/// Create a BoxedIntoIter from a full boxed array.
+pub fn into_iter<T, const N: usize>(arr: Box<[T;N]>) -> BoxedIntoIter<T,N> {
+	BoxedIntoIter {
+		arr: unsafe { Box::from_raw(Box::into_raw(arr) as *mut [MaybeUninit<T>; N]) },
+		alive: 0..N,
+	}
+}
+
+impl<T, const N: usize> BoxedIntoIter<T,N> {
+	/// Returns the remaining elements of this iterator as a slice.
+	pub fn as_slice(&self) -> &[T] {
+		unsafe {
+			let start = self.arr.as_ptr().add(self.alive.start) as *const T;
+			core::slice::from_raw_parts(start, self.alive.len())
+		}
+	}
+
+	/// Returns the remaining elements of this iterator as a mutable slice.
+	pub fn as_mut_slice(&mut self) -> &mut [T] {
+		unsafe {
+			let start = self.arr.as_mut_ptr().add(self.alive.start) as *mut T;
+			core::slice::from_raw_parts_mut(start, self.alive.len())
+		}
+	}
+}
+
+impl<T, const N: usize> Drop for BoxedIntoIter<T,N> {
+	fn drop(&mut self) {
+		// Drop the remaining elements in place. The allocation itself is freed when `arr` is dropped.
+		unsafe { core::ptr::drop_in_place(self.as_mut_slice()) }
+	}
+}
+
+impl<T, const N: usize> Iterator for BoxedIntoIter<T,N> {
+	type Item = T;
+	fn next(&mut self) -> Option<T> {
+		self.alive.next().map(|i| unsafe {extract(&mut self.arr, i)})
+	}
+	// Implemented in terms of ExactSizeIterator.
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let out = self.len();
+		(out, Some(out))
+	}
+}
+
+impl<T, const N: usize> DoubleEndedIterator for BoxedIntoIter<T,N> {
+	fn next_back(&mut self) -> Option<T> {
+		self.alive.next_back().map(|i| unsafe {extract(&mut self.arr, i)})
+	}
+}
+
+impl<T, const N: usize> ExactSizeIterator for BoxedIntoIter<T,N> {
+	fn len(&self) -> usize {
+		self.alive.len()
+	}
+}
+impl<T, const N: usize> FusedIterator for BoxedIntoIter<T,N> {}
+#[cfg(feature = "nightly")]
+unsafe impl<T, const N: usize> TrustedLen for BoxedIntoIter<T,N> {}
+
+impl<T: core::fmt::Debug, const N: usize> core::fmt::Debug for BoxedIntoIter<T,N> {
+	fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+		self.as_slice().fmt(f)
+	}
+}
+
+
+/// The error returned by [`try_new_boxed`].
+///
+/// [`try_new_boxed`]: ./fn.try_new_boxed.html
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NewBoxedError<E> {
+	/// The array could not be allocated.
+	Alloc(TryReserveError),
+	/// The closure returned an error.
+	Closure(E),
+}
+
+impl<E: core::fmt::Display> core::fmt::Display for NewBoxedError<E> {
+	fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+		match self {
+			NewBoxedError::Alloc(e) => e.fmt(f),
+			NewBoxedError::Closure(e) => e.fmt(f),
+		}
+	}
+}
+
+#[cfg(feature = "std")]
+impl<E: std::error::Error + 'static> std::error::Error for NewBoxedError<E> {
+	fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+		match self {
+			NewBoxedError::Alloc(e) => Some(e),
+			NewBoxedError::Closure(e) => Some(e),
+		}
+	}
+}
+
+/// Allocate a zeroed boxed array.
+pub fn zeroed<T: Zeroable, const N: usize>() -> Box<[T;N]> {
+	let layout = Layout::new::<[T;N]>();
+	if layout.size() == 0 {
+		return unsafe { Box::from_raw(NonNull::dangling().as_ptr()) };
+	}
+	unsafe {
+		let ptr = alloc_zeroed(layout);
+		if ptr.is_null() {
+			handle_alloc_error(layout);
+		}
+		Box::from_raw(ptr as *mut [T;N])
+	}
+}
+
+
+/// Convert a vector of exactly `N` elements into a boxed array, without reallocating if the capacity is exact.
+pub fn from_vec<T, const N: usize>(v: Vec<T>) -> Box<[T;N]> {
+	match core::convert::TryFrom::try_from(v.into_boxed_slice()) {
+		Ok(arr) => arr,
+		Err(_) => unreachable!("vector does not have exactly N elements"),
+	}
+}
+
+
+/// The state of a `map_in_place` that has not yet finished.
+///
+/// Elements `[0..mapped]` have been mapped into `U`s.
+/// Element `mapped` has been moved out, and is being passed to the closure.
+/// Elements `[mapped+1..N]` are the remaining `T`s.
+struct InPlaceMap<T, U, const N: usize> {
+	ptr: *mut [MaybeUninit<T>; N],
+	mapped: usize,
+	_marker: PhantomData<(T, U)>,
+}
+
+impl<T, U, const N: usize> Drop for InPlaceMap<T,U,N> {
+	// This only runs if the closure panics.
+	fn drop(&mut self) {
+		unsafe {
+			let done = core::slice::from_raw_parts_mut(self.ptr as *mut U, self.mapped);
+			let rest = core::slice::from_raw_parts_mut((self.ptr as *mut T).add(self.mapped + 1), N - self.mapped - 1);
+			core::ptr::drop_in_place(done);
+			core::ptr::drop_in_place(rest);
+			drop(Box::from_raw(self.ptr));
+		}
+	}
+}
+
+/// Map a boxed array, writing each output over the input it came from.
+///
+/// `T` and `U` must have the same size and alignment.
+pub fn map_in_place<T, U, const N: usize>(arr: Box<[T;N]>, mut f: impl FnMut(T) -> U) -> Box<[U;N]> {
+	assert!(core::alloc::Layout::new::<T>() == core::alloc::Layout::new::<U>());
+	let mut state = InPlaceMap::<T,U,N> {
+		ptr: Box::into_raw(arr) as *mut [MaybeUninit<T>; N],
+		mapped: 0,
+		_marker: PhantomData,
+	};
+	while state.mapped < N {
+		unsafe {
+			let slot = (state.ptr as *mut T).add(state.mapped);
+			let out = f(slot.read());
+			(slot as *mut U).write(out);
+		}
+		state.mapped += 1;
+	}
+	let state = ManuallyDrop::new(state);
+	unsafe { Box::from_raw(state.ptr as *mut [U;N]) }
+}
+
+/// Transpose a boxed 2D array, moving the elements around inside the same allocation.
+pub fn transpose_in_place<T, const M: usize, const N: usize>(arr: Box<[[T;M];N]>) -> Box<[[T;N];M]> {
+	// Zero-sized elements have nothing to move. `M * N` may also overflow for them.
+	if core::mem::size_of::<T>() == 0 {
+		return unsafe { Box::from_raw(Box::into_raw(arr) as *mut [[T;N];M]) };
+	}
+	let len = M * N;
+	let mut visited = alloc::vec![0u64; len.div_ceil(64)];
+	// Follow each cycle of the permutation once, marking the indices it visits.
+	// Nothing in this loop can panic, so no elements can be leaked or duplicated.
+	let ptr = Box::into_raw(arr) as *mut T;
+	// The element in row `j` and column `i` moves from flat index `j*M + i` to flat index `i*N + j`.
+	let dest = |k: usize| (k % M) * N + k / M;
+	for start in 0..len {
+		if visited[start / 64] & (1 << (start % 64)) != 0 {
+			continue;
+		}
+		unsafe {
+			let mut carry = ptr.add(start).read();
+			let mut pos = start;
+			loop {
+				visited[pos / 64] |= 1 << (pos % 64);
+				pos = dest(pos);
+				if pos == start {
+					ptr.add(start).write(carry);
+					break;
+				}
+				carry = core::mem::replace(&mut *ptr.add(pos), carry);
+			}
+		}
+	}
+	unsafe { Box::from_raw(ptr as *mut [[T;N];M]) }
+}
+
+/// The inputs of a `map_in` that has not yet finished.
+///
+/// Elements `[0..taken]` have been moved out. Elements `[taken..N]` are the remaining `T`s.
+#[cfg(feature = "allocator_api")]
+struct MapInInputs<'a, T, const N: usize> {
+	arr: &'a mut [MaybeUninit<T>; N],
+	taken: usize,
+}
+
+#[cfg(feature = "allocator_api")]
+impl<T, const N: usize> Drop for MapInInputs<'_,T,N> {
+	// This does nothing unless the closure panics.
+	fn drop(&mut self) {
+		unsafe { core::ptr::drop_in_place(&mut self.arr[self.taken..] as *mut [MaybeUninit<T>] as *mut [T]) }
+	}
+}
+
+/// Map a boxed array into a fresh allocation from `alloc`.
+#[cfg(feature = "allocator_api")]
+pub fn map_in<T, U, A: core::alloc::Allocator, B: core::alloc::Allocator, const N: usize>(arr: Box<[T;N], A>, alloc: B, mut f: impl FnMut(T) -> U) -> Box<[U;N], B> {
+	let (ptr, a) = Box::into_raw_with_allocator(arr);
+	// Freed when this function returns or unwinds, after the remaining inputs are dropped.
+	let mut input = unsafe { Box::from_raw_in(ptr as *mut [MaybeUninit<T>; N], a) };
+	let mut inputs = MapInInputs { arr: &mut input, taken: 0 };
+	let mut output = Box::<[U;N], B>::new_uninit_in(alloc);
+	let result = try_init_slot::<U, core::convert::Infallible, N>(&mut output, |i| {
+		inputs.taken = i + 1;
+		Ok(f(unsafe { inputs.arr[i].assume_init_read() }))
+	});
+	match result {
+		Ok(_) => {}
+		Err(e) => match e {},
+	}
+	unsafe { output.assume_init() }
+}
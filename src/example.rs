@@ -1,24 +1,23 @@
 //! # Example: Implementing Matrices
 //!
 //! ```
-//! #![feature(const_generics)]
 //! # use array_helpers::*;
 //! 
 //! #[derive(Copy, Clone)]
 //! /// A linear transformation, implemented as a matrix.
 //! struct Transform<const A: usize, const B: usize>([[f64;B];A]);
 //! 
-//! fn identity<const N: usize>() -> Transform<{N},{N}> {
+//! fn identity<const N: usize>() -> Transform<N,N> {
 //!     Transform(new(|j| new(|i| if i == j {1.} else {0.})))
 //! }
 //! 
-//! impl<const A: usize, const B: usize, const C: usize> core::ops::Mul<Transform<{A},{B}>> for Transform<{B},{C}> {
-//!     type Output = Transform<{A},{C}>;
-//!     fn mul(self, other: Transform<{A},{B}>) -> Transform<{A},{C}> {
+//! impl<const A: usize, const B: usize, const C: usize> core::ops::Mul<Transform<A,B>> for Transform<B,C> {
+//!     type Output = Transform<A,C>;
+//!     fn mul(self, other: Transform<A,B>) -> Transform<A,C> {
 //!         Transform(other.0.map(|u| {
 //!             self.0
 //!                 .transpose()
-//!                 .map(|v| u.zip(v).into_iter().map(|(a, b)| a * b).sum())
+//!                 .map(|v| Array::into_iter(u.zip(v)).map(|(a, b)| a * b).sum())
 //!         }))
 //!     }
 //! }
@@ -26,7 +25,7 @@
 //! 
 //! 
 //! 
-//! impl<const A: usize, const B: usize> PartialEq for Transform<{A},{B}> {
+//! impl<const A: usize, const B: usize> PartialEq for Transform<A,B> {
 //!     fn eq(&self, other: &Self) -> bool {
 //!         for i in 0..A {
 //!             for j in 0..B {
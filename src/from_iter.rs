@@ -0,0 +1,126 @@
+use core::iter::{Chain, Once};
+use crate::utils::*;
+
+
+/// An array whose first few elements are initialized.
+///
+/// This is returned when an iterator runs out before filling an array.
+/// It dereferences to a slice of the elements that were collected.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use array_helpers::*;
+/// let partial = match (0..3).collect_array::<5>() {
+///     Err(FromIterError::TooFew(partial)) => partial,
+///     _ => unreachable!(),
+/// };
+/// assert_eq!(*partial, [0, 1, 2]);
+/// assert_eq!(partial.complete(|i| 10 * i), [0, 1, 2, 30, 40]);
+/// ```
+pub struct PartialArray<T, const N: usize>(ArrayBuilder<T,N>);
+
+impl<T, const N: usize> PartialArray<T,N> {
+	/// The number of initialized elements.
+	pub fn len(&self) -> usize {
+		self.0.len()
+	}
+
+	/// Returns `true` if no elements are initialized.
+	pub fn is_empty(&self) -> bool {
+		self.0.len() == 0
+	}
+
+	/// The initialized elements.
+	pub fn as_slice(&self) -> &[T] {
+		self.0.as_slice()
+	}
+
+	/// The initialized elements.
+	pub fn as_mut_slice(&mut self) -> &mut [T] {
+		self.0.as_mut_slice()
+	}
+
+	/// Fills in the missing elements by calling a closure on each of their indices.
+	pub fn complete(mut self, mut f: impl FnMut(usize) -> T) -> [T;N] {
+		for i in self.0.len()..N {
+			self.0.push(f(i));
+		}
+		self.0.finish()
+	}
+}
+
+impl<T, const N: usize> core::ops::Deref for PartialArray<T,N> {
+	type Target = [T];
+	fn deref(&self) -> &[T] {
+		self.as_slice()
+	}
+}
+
+impl<T, const N: usize> core::ops::DerefMut for PartialArray<T,N> {
+	fn deref_mut(&mut self) -> &mut [T] {
+		self.as_mut_slice()
+	}
+}
+
+impl<T: core::fmt::Debug, const N: usize> core::fmt::Debug for PartialArray<T,N> {
+	fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+		self.as_slice().fmt(f)
+	}
+}
+
+
+/// The error returned when an iterator does not yield exactly `N` items.
+///
+/// This is returned by [`from_iter`] and [`collect_array`].
+///
+/// [`from_iter`]: ./fn.from_iter.html
+/// [`collect_array`]: ./trait.IteratorExt.html#method.collect_array
+pub enum FromIterError<T, I, const N: usize> {
+	/// The iterator ran out early. Contains the items it did yield.
+	TooFew(PartialArray<T,N>),
+	/// The iterator had more than `N` items.
+	/// Contains the first `N` items, and an iterator over the rest.
+	TooMany([T;N], Chain<Once<T>, I>),
+}
+
+impl<T: core::fmt::Debug, I, const N: usize> core::fmt::Debug for FromIterError<T,I,N> {
+	fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+		match self {
+			FromIterError::TooFew(partial) => f.debug_tuple("TooFew").field(partial).finish(),
+			FromIterError::TooMany(arr, _) => f.debug_tuple("TooMany").field(&&arr[..]).finish(),
+		}
+	}
+}
+
+impl<T, I, const N: usize> core::fmt::Display for FromIterError<T,I,N> {
+	fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+		match self {
+			FromIterError::TooFew(partial) => write!(f, "expected {} items, but the iterator only had {}", N, partial.len()),
+			FromIterError::TooMany(_, _) => write!(f, "expected {} items, but the iterator had more", N),
+		}
+	}
+}
+
+#[cfg(feature = "std")]
+impl<T: core::fmt::Debug, I, const N: usize> std::error::Error for FromIterError<T,I,N> {}
+
+
+/// Collect exactly `N` items from an iterator into an array.
+#[allow(clippy::type_complexity)]
+pub fn collect<I: IntoIterator, const N: usize>(iter: I) -> Result<[I::Item;N], FromIterError<I::Item, I::IntoIter, N>> {
+	let mut iter = iter.into_iter();
+	let mut arr = ArrayBuilder::new();
+	for _ in 0..N {
+		match iter.next() {
+			Some(item) => arr.push(item),
+			None => return Err(FromIterError::TooFew(PartialArray(arr))),
+		}
+	}
+	match iter.next() {
+		None => Ok(arr.finish()),
+		Some(extra) => Err(FromIterError::TooMany(arr.finish(), core::iter::once(extra).chain(iter))),
+	}
+}
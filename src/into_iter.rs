@@ -1,6 +1,8 @@
 use core::iter::*;
-use core::mem::MaybeUninit;
+use core::mem::{ManuallyDrop, MaybeUninit};
+use core::ops::Range;
 use crate::utils::*;
+use crate::array_vec::{self, ArrayVec};
 
 
 /// Array Iterator
@@ -12,140 +14,287 @@ use crate::utils::*;
 /// Basic usage:
 ///
 /// ```
+/// # use array_helpers::*;
 /// // First, we create an array:
 /// let array: [usize; 4] = [0, 1, 2, 3];
 ///
 /// // Then, we iterate over it.
-/// for element in array.into_iter() {
-/// 	println!("{}", element);
+/// for element in Array::into_iter(array) {
+///     println!("{}", element);
 /// }
 /// ```
 ///
+/// Iterating in reverse:
+///
+/// ```
+/// # use array_helpers::*;
+/// let array = [String::from("a"), String::from("b"), String::from("c")];
+/// let reversed: Vec<String> = Array::into_iter(array).rev().collect();
+/// assert_eq!(reversed, ["c", "b", "a"]);
+/// ```
+///
 /// [`into_iter`]: ./trait.Array.html#tymethod.into_iter
 pub struct IntoIter<T, const N: usize> {
-	/// arr[0..ptr] is uninitialized. arr[ptr..N] are the remaining elements in the iterator.
+	/// arr[alive] are the remaining elements in the iterator. Everything outside of that range is uninitialized.
 	arr: [MaybeUninit<T>; N],
-	/// The index of the next item in the iterator.
-	ptr: usize,
+	/// The indices of the elements that have not yet been yielded.
+	alive: Range<usize>,
 }
 
 /// Create an IntoIter from a full array.
-pub fn new<T, const N: usize>(arr: [T;N]) -> IntoIter<T,{N}> {
+pub fn new<T, const N: usize>(arr: [T;N]) -> IntoIter<T,N> {
 	IntoIter {
 		arr: push_maybe_uninit(MaybeUninit::new(arr)),
-		ptr: 0,
+		alive: 0..N,
+	}
+}
+
+/// Create an IntoIter from an array whose elements in `alive` are initialized.
+pub unsafe fn from_raw_parts<T, const N: usize>(arr: [MaybeUninit<T>; N], alive: Range<usize>) -> IntoIter<T,N> {
+	IntoIter { arr, alive }
+}
+
+impl<T, const N: usize> IntoIter<T,N> {
+	/// Returns the remaining elements of this iterator as a slice.
+	///
+	/// # Example
+	///
+	/// Basic usage:
+	///
+	/// ```
+	/// # use array_helpers::*;
+	/// let mut iter = Array::into_iter([1, 2, 3, 4]);
+	/// iter.next();
+	/// iter.next_back();
+	/// assert_eq!(iter.as_slice(), &[2, 3]);
+	/// ```
+	pub fn as_slice(&self) -> &[T] {
+		unsafe {
+			let start = self.arr.as_ptr().add(self.alive.start) as *const T;
+			core::slice::from_raw_parts(start, self.alive.len())
+		}
+	}
+
+	/// Returns the remaining elements of this iterator as a mutable slice.
+	///
+	/// # Example
+	///
+	/// Basic usage:
+	///
+	/// ```
+	/// # use array_helpers::*;
+	/// let mut iter = Array::into_iter([1, 2, 3, 4]);
+	/// iter.next();
+	/// iter.as_mut_slice()[0] = 20;
+	/// assert_eq!(iter.next(), Some(20));
+	/// ```
+	pub fn as_mut_slice(&mut self) -> &mut [T] {
+		unsafe {
+			let start = self.arr.as_mut_ptr().add(self.alive.start) as *mut T;
+			core::slice::from_raw_parts_mut(start, self.alive.len())
+		}
+	}
+
+	/// Returns the original array, if no elements have been taken out of the iterator yet.
+	///
+	/// # Example
+	///
+	/// Basic usage:
+	///
+	/// ```
+	/// # use array_helpers::*;
+	/// let iter = Array::into_iter([1, 2, 3]);
+	/// assert_eq!(iter.try_into_array().ok(), Some([1, 2, 3]));
+	///
+	/// let mut iter = Array::into_iter([1, 2, 3]);
+	/// iter.next_back();
+	/// assert_eq!(iter.try_into_array().unwrap_err().as_slice(), &[1, 2]);
+	/// ```
+	pub fn try_into_array(self) -> Result<[T;N], Self> {
+		if self.alive.start == 0 && self.alive.end == N {
+			let this = ManuallyDrop::new(self);
+			Ok(unsafe { assume_init_array(core::ptr::read(&this.arr)) })
+		} else {
+			Err(self)
+		}
+	}
+
+	/// Moves the remaining elements into an [`ArrayVec`].
+	///
+	/// # Example
+	///
+	/// Basic usage:
+	///
+	/// ```
+	/// # use array_helpers::*;
+	/// let mut iter = Array::into_iter([1, 2, 3, 4]);
+	/// iter.next();
+	/// assert_eq!(*iter.into_array_vec(), [2, 3, 4]);
+	/// ```
+	///
+	/// [`ArrayVec`]: ./struct.ArrayVec.html
+	pub fn into_array_vec(self) -> ArrayVec<T,N> {
+		let this = ManuallyDrop::new(self);
+		unsafe {
+			let mut arr = core::ptr::read(&this.arr);
+			let len = this.alive.len();
+			// Shift the remaining elements to the front.
+			let ptr = arr.as_mut_ptr();
+			core::ptr::copy(ptr.add(this.alive.start), ptr, len);
+			array_vec::from_raw_parts(arr, len)
+		}
+	}
+
+	#[cfg(feature = "alloc")]
+	/// Moves the remaining elements into a `Vec`.
+	///
+	/// # Example
+	///
+	/// Basic usage:
+	///
+	/// ```
+	/// # use array_helpers::*;
+	/// let mut iter = Array::into_iter([1, 2, 3, 4]);
+	/// iter.next();
+	/// assert_eq!(iter.into_vec(), vec![2, 3, 4]);
+	/// ```
+	pub fn into_vec(self) -> alloc::vec::Vec<T> {
+		self.collect()
 	}
 }
 
-impl<T, const N: usize> Drop for IntoIter<T,{N}> {
+impl<T, const N: usize> Drop for IntoIter<T,N> {
 	fn drop(&mut self) {
-		// Exhaust the iterator. Afterwards, the entire array will be uninitialized, and can be safely dropped without memory leakage.
-		self.for_each(core::mem::drop);
+		// Drop the remaining elements in place. Afterwards, the entire array is uninitialized, and can be safely dropped without memory leakage.
+		// If one of the destructors panics, `drop_in_place` still drops the elements after it.
+		unsafe { core::ptr::drop_in_place(self.as_mut_slice()) }
 	}
 }
 
-impl<T, const N: usize> Iterator for IntoIter<T,{N}> {
+impl<T, const N: usize> Iterator for IntoIter<T,N> {
 	type Item = T;
 	fn next(&mut self) -> Option<T> {
-		if self.ptr < N {
-			let out = unsafe {extract(&mut self.arr, self.ptr)};
-			self.ptr += 1;
-			Some(out)
-		} else {
-			None
-		}
+		self.alive.next().map(|i| unsafe {extract(&mut self.arr, i)})
 	}
 	// Implemented in terms of ExactSizeIterator.
 	fn size_hint(&self) -> (usize, Option<usize>) {
 		let out = self.len();
 		(out, Some(out))
 	}
+	fn nth(&mut self, n: usize) -> Option<T> {
+		// Skip past the elements before dropping them, so a panicking destructor cannot cause a double drop.
+		let skipped = unsafe {
+			let start = self.alive.start;
+			self.alive.start += n.min(self.alive.len());
+			let ptr = self.arr.as_mut_ptr().add(start) as *mut T;
+			core::slice::from_raw_parts_mut(ptr, self.alive.start - start)
+		};
+		unsafe { core::ptr::drop_in_place(skipped) };
+		self.next()
+	}
+	fn count(self) -> usize {
+		self.len()
+	}
+	fn last(mut self) -> Option<T> {
+		self.next_back()
+	}
+	#[cfg(feature = "nightly")]
 	fn try_fold<B,F,R>(&mut self, mut state: B, mut f: F) -> R where
 		F: FnMut(B, T) -> R,
-		R: core::ops::Try<Ok = B>,
+		R: core::ops::Try<Output = B>,
 	{
-		while self.ptr < N {
-			let item = unsafe {extract(&mut self.arr, self.ptr)};
-			self.ptr += 1;
+		for i in self.alive.by_ref() {
+			let item = unsafe {extract(&mut self.arr, i)};
 			state = f(state, item)?;
 		}
-		R::from_ok(state)
+		R::from_output(state)
+	}
+}
+
+impl<T, const N: usize> DoubleEndedIterator for IntoIter<T,N> {
+	fn next_back(&mut self) -> Option<T> {
+		self.alive.next_back().map(|i| unsafe {extract(&mut self.arr, i)})
+	}
+	fn nth_back(&mut self, n: usize) -> Option<T> {
+		// Skip past the elements before dropping them, so a panicking destructor cannot cause a double drop.
+		let skipped = unsafe {
+			let end = self.alive.end;
+			self.alive.end -= n.min(self.alive.len());
+			let start = self.arr.as_mut_ptr().add(self.alive.end) as *mut T;
+			core::slice::from_raw_parts_mut(start, end - self.alive.end)
+		};
+		unsafe { core::ptr::drop_in_place(skipped) };
+		self.next_back()
+	}
+	fn rfold<B,F>(mut self, mut state: B, mut f: F) -> B where
+		F: FnMut(B, T) -> B,
+	{
+		while let Some(item) = self.next_back() {
+			state = f(state, item);
+		}
+		state
 	}
 }
 
-impl<T, const N: usize> ExactSizeIterator for IntoIter<T,{N}> {
+impl<T, const N: usize> ExactSizeIterator for IntoIter<T,N> {
 	fn len(&self) -> usize {
-		N - self.ptr
+		self.alive.len()
 	}
 }
-impl<T, const N: usize> FusedIterator for IntoIter<T,{N}> {}
-unsafe impl<T, const N: usize> TrustedLen for IntoIter<T,{N}> {}
+impl<T, const N: usize> FusedIterator for IntoIter<T,N> {}
+#[cfg(feature = "nightly")]
+unsafe impl<T, const N: usize> TrustedLen for IntoIter<T,N> {}
 
 
-impl<T: Clone, const N: usize> Clone for IntoIter<T,{N}> {
+impl<T: Clone, const N: usize> Clone for IntoIter<T,N> {
 	fn clone(&self) -> Self {
-		let mut arr = push_maybe_uninit(MaybeUninit::uninit());
 		// Clone only the initialized indices.
-		unsafe {
-			for i in self.ptr..N {
-				arr[i] = MaybeUninit::new(self.arr[i].get_ref().clone())
-			}
+		// The clone's live range grows one element at a time, so if a `clone` panics, the clones made so far are dropped.
+		let mut out = Self {
+			arr: uninit_array(),
+			alive: self.alive.start..self.alive.start,
+		};
+		for item in self.as_slice() {
+			out.arr[out.alive.end] = MaybeUninit::new(item.clone());
+			out.alive.end += 1;
 		}
-		Self { arr, ptr: self.ptr }
+		out
 	}
 }
 
-impl<T: PartialEq, const N: usize> PartialEq for IntoIter<T,{N}> {
+impl<T: PartialEq, const N: usize> PartialEq for IntoIter<T,N> {
 	fn eq(&self, other: &Self) -> bool {
-		unsafe {
-			let slice1 = core::mem::transmute::<&[MaybeUninit<T>], &[T]>(&self.arr[self.ptr..N]);
-			let slice2 = core::mem::transmute::<&[MaybeUninit<T>], &[T]>(&other.arr[other.ptr..N]);
-			slice1.eq(slice2)
-		}
+		self.as_slice().eq(other.as_slice())
 	}
 }
 
-impl<T: Eq, const N: usize> Eq for IntoIter<T,{N}> {}
+impl<T: Eq, const N: usize> Eq for IntoIter<T,N> {}
 
-impl<T: PartialOrd, const N: usize> PartialOrd for IntoIter<T,{N}> {
+impl<T: PartialOrd, const N: usize> PartialOrd for IntoIter<T,N> {
 	fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
-		unsafe {
-			let slice1 = core::mem::transmute::<&[MaybeUninit<T>], &[T]>(&self.arr[self.ptr..N]);
-			let slice2 = core::mem::transmute::<&[MaybeUninit<T>], &[T]>(&other.arr[other.ptr..N]);
-			slice1.partial_cmp(slice2)
-		}
+		self.as_slice().partial_cmp(other.as_slice())
 	}
 }
 
-impl<T: Ord, const N: usize> Ord for IntoIter<T,{N}> {
+impl<T: Ord, const N: usize> Ord for IntoIter<T,N> {
 	fn cmp(&self, other: &Self) -> core::cmp::Ordering {
-		unsafe {
-			let slice1 = core::mem::transmute::<&[MaybeUninit<T>], &[T]>(&self.arr[self.ptr..N]);
-			let slice2 = core::mem::transmute::<&[MaybeUninit<T>], &[T]>(&other.arr[other.ptr..N]);
-			slice1.cmp(slice2)
-		}
+		self.as_slice().cmp(other.as_slice())
 	}
 }
 
-impl<T: core::hash::Hash, const N: usize> core::hash::Hash for IntoIter<T,{N}> {
+impl<T: core::hash::Hash, const N: usize> core::hash::Hash for IntoIter<T,N> {
 	fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
-		unsafe {
-			let slice = core::mem::transmute::<&[MaybeUninit<T>], &[T]>(&self.arr[self.ptr..N]);
-			slice.hash(state)
-		}
+		self.as_slice().hash(state)
 	}
 }
 
-impl<T: core::fmt::Debug, const N: usize> core::fmt::Debug for IntoIter<T,{N}> {
+impl<T: core::fmt::Debug, const N: usize> core::fmt::Debug for IntoIter<T,N> {
 	fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
-		unsafe {
-			let slice = core::mem::transmute::<&[MaybeUninit<T>], &[T]>(&self.arr[self.ptr..N]);
-			slice.fmt(f)
-		}
+		self.as_slice().fmt(f)
 	}
 }
 
-impl<T: Default, const N: usize> Default for IntoIter<T,{N}> {
+impl<T: Default, const N: usize> Default for IntoIter<T,N> {
 	/// Equivalent to `<[T;N]>::default().into_iter()`
 	fn default() -> Self {
 		new(crate::new(|_| Default::default()))
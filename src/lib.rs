@@ -1,9 +1,7 @@
 #![cfg_attr(not(feature = "std"), no_std)]
-#![feature(const_fn)]
-#![feature(const_generics)]
-#![feature(maybe_uninit_ref)]
-#![feature(trusted_len)]
-#![feature(try_trait)]
+#![cfg_attr(feature = "nightly", feature(trusted_len))]
+#![cfg_attr(feature = "nightly", feature(try_trait_v2))]
+#![cfg_attr(feature = "allocator_api", feature(allocator_api))]
 
 #![doc(html_root_url = "https://docs.rs/array-helpers/0.0.1")]
 
@@ -12,8 +10,14 @@
 //! This crate provides utilities for initializing, consuming, and transforming arrays.
 //! All methods are length-generic.
 //!
-//! This crate uses the extremely new `const_generics` feature.
-//! As such, it requires nightly Rust.
+//! This crate is built on const generics, and works on stable Rust 1.82 or later.
+//! Enabling the `nightly` feature adds a few optimizations that depend on unstable features.
+//!
+//! The crate is `no_std` when the default `std` feature is disabled.
+//! The `alloc` feature, which `std` implies, keeps the heap helpers such as [`new_boxed`] available on such targets.
+//! On nightly Rust, the `allocator_api` feature adds [`new_in`] and [`map_in`], which work with custom allocators.
+//!
+//! The [`raw`] module exposes the `MaybeUninit` plumbing that the rest of the crate is built on.
 //!
 //! # Rationale
 //!
@@ -88,9 +92,9 @@
 //!     Branch(Box<[Self;8]>),
 //! }
 //! 
-//! # #[cfg(not(feature = "std"))]
+//! # #[cfg(not(feature = "alloc"))]
 //! # let tree: OctTree<usize> = OctTree::Branch(Box::new(new(OctTree::Leaf)));
-//! # #[cfg(feature = "std")]
+//! # #[cfg(feature = "alloc")]
 //! let tree: OctTree<usize> = OctTree::Branch(new_boxed(OctTree::Leaf));
 //! 
 //! impl<T> OctTree<T> {
@@ -99,7 +103,11 @@
 //!             OctTree::Leaf(t) => OctTree::Leaf(f(t)),
 //!             OctTree::Branch(subtrees) => {
 //!                 let mut closure: Box<dyn FnMut(T) -> U> = Box::new(f);
-//!                 OctTree::Branch(Box::new(subtrees.map(|subtree| subtree.map(&mut closure))))
+//! #               #[cfg(not(feature = "alloc"))]
+//! #               let subtrees = Box::new(subtrees.map(|subtree| subtree.map(&mut closure)));
+//! #               #[cfg(feature = "alloc")]
+//!                 let subtrees = subtrees.map(|subtree| subtree.map(&mut closure));
+//!                 OctTree::Branch(subtrees)
 //!             }
 //!         }
 //!     }
@@ -112,42 +120,157 @@
 //!
 //! [Implementing matrices]
 //!
-//! # Warning
-//!
-//! `warning: the feature 'const_generics' is incomplete and may cause the compiler to crash`
-//!
 //! # Reference
 //!
 //! Most of The methods in this crate can be found inside the provided traits,
 //! as this is the only way to add methods to `[T;N]`.
 //! Here is a list of all provided methods:
 //!
-//! | Function      | Arguments         | Result                    |
-//! |---------------|-------------------|---------------------------|
-//! | [`new`]       | `usize -> T`      | `[T;N]`                   |
-//! | [`new_boxed`] | `usize -> T`      | `Box<[T;N]>`              |
-//! | [`into_iter`] | `[T;N]`           | `impl Iterator<Item = T>` |
-//! | [`map`]       | `[T;N]`, `T -> U` | `[U;N]`                   |
-//! | [`zip`]       | `[T;N]`, `[U;N]`  | `[(T,U);N]`               |
-//! | [`unzip`]     | `[(T,U);N]`       | `[T;N]`, `[U;N]`          |
-//! | [`transpose`] | `[[T;M];N]`       | `[[T;N];M]`               |
+//! | Function                         | Arguments                                         | Result                                |
+//! |----------------------------------|---------------------------------------------------|---------------------------------------|
+//! | [`new`]                          | `usize -> T`                                      | `[T;N]`                               |
+//! | [`try_new`]                      | `usize -> Result<T,E>`                            | `Result<[T;N],E>`                     |
+//! | [`try_new_option`]               | `usize -> Option<T>`                              | `Option<[T;N]>`                       |
+//! | [`new_with_prefix`]              | `(usize, &[T]) -> T`                              | `[T;N]`                               |
+//! | [`unfold`]                       | `S`, `&mut S -> T`                                | `[T;N]`                               |
+//! | [`init_in_place`]                | `&mut MaybeUninit<[T;N]>`, `usize -> T`           | `&mut [T;N]`                          |
+//! | [`try_init_in_place`]            | `&mut MaybeUninit<[T;N]>`, `usize -> Result<T,E>` | `Result<&mut [T;N],E>`                |
+//! | [`from_iter`]                    | `impl IntoIterator<Item = T>`                     | `Result<[T;N],FromIterError>`         |
+//! | [`collect_array`]                | `impl Iterator<Item = T>`                         | `Result<[T;N],FromIterError>`         |
+//! | [`new_boxed`]                    | `usize -> T`                                      | `Box<[T;N]>`                          |
+//! | [`try_new_boxed`]                | `usize -> Result<T,E>`                            | `Result<Box<[T;N]>,NewBoxedError<E>>` |
+//! | [`new_boxed_zeroed`]             | `()`                                              | `Box<[T;N]>`                          |
+//! | [`new_in`]                       | `A`, `usize -> T`                                 | `Box<[T;N],A>`                        |
+//! | [`map_in`]                       | `Box<[T;N],A>`, `B`, `T -> U`                     | `Box<[U;N],B>`                        |
+//! | [`new_rc`]                       | `usize -> T`                                      | `Rc<[T;N]>`                           |
+//! | [`try_new_rc`]                   | `usize -> Result<T,E>`                            | `Result<Rc<[T;N]>,E>`                 |
+//! | [`new_arc`]                      | `usize -> T`                                      | `Arc<[T;N]>`                          |
+//! | [`try_new_arc`]                  | `usize -> Result<T,E>`                            | `Result<Arc<[T;N]>,E>`                |
+//! | [`into_iter`]                    | `[T;N]`                                           | `impl Iterator<Item = T>`             |
+//! | [`map`]                          | `[T;N]`, `T -> U`                                 | `[U;N]`                               |
+//! | [`zip`]                          | `[T;N]`, `[U;N]`                                  | `[(T,U);N]`                           |
+//! | [`zip_with`]                     | `[T;N]`, `[U;N]`, `(T,U) -> V`                    | `[V;N]`                               |
+//! | [`zip3`]                         | `[T;N]`, `[U;N]`, `[V;N]`                         | `[(T,U,V);N]`                         |
+//! | [`enumerate`]                    | `[T;N]`                                           | `[(usize,T);N]`                       |
+//! | [`map_indexed`]                  | `[T;N]`, `(usize,T) -> U`                         | `[U;N]`                               |
+//! | [`try_map`]                      | `[T;N]`, `T -> Result<U,E>`                       | `Result<[U;N],E>`                     |
+//! | [`try_zip_with`]                 | `[T;N]`, `[U;N]`, `(T,U) -> Result<V,E>`          | `Result<[V;N],E>`                     |
+//! | [`each_ref`]                     | `&[T;N]`                                          | `[&T;N]`                              |
+//! | [`each_mut`]                     | `&mut [T;N]`                                      | `[&mut T;N]`                          |
+//! | [`map_ref`]                      | `&[T;N]`, `&T -> U`                               | `[U;N]`                               |
+//! | [`map_mut`]                      | `&mut [T;N]`, `&mut T -> U`                       | `[U;N]`                               |
+//! | [`unzip`]                        | `[(T,U,...);N]`                                   | `[T;N]`, `[U;N]`, ...                 |
+//! | [`zip_all`]                      | `([T;N],[U;N],...)`                               | `[(T,U,...);N]`                       |
+//! | [`transpose`]                    | `[[T;M];N]`                                       | `[[T;N];M]`                           |
+//! | [`transpose_option`]             | `[Option<T>;N]`                                   | `Option<[T;N]>`                       |
+//! | [`transpose_result`]             | `[Result<T,E>;N]`                                 | `Result<[T;N],E>`                     |
+//! | [`transpose_array`]              | `Option<[T;N]>`                                   | `[Option<T>;N]`                       |
+//! | [`flatten`]                      | `[[T;M];N]`                                       | `[T;M*N]`                             |
+//! | [`unflatten`]                    | `[T;K*L]`                                         | `[[T;K];L]`                           |
+//! | [`concat`]                       | `[T;N]`, `[T;M]`                                  | `[T;N+M]`                             |
+//! | [`split_at`]                     | `[T;K+R]`                                         | `[T;K]`, `[T;R]`                      |
+//! | [`push`]                         | `[T;N]`, `T`                                      | `[T;N+1]`                             |
+//! | [`pop`]                          | `[T;N+1]`                                         | `[T;N]`, `T`                          |
+//! | [`split_first`]                  | `[T;N+1]`                                         | `T`, `[T;N]`                          |
+//! | [`split_last`]                   | `[T;N+1]`                                         | `[T;N]`, `T`                          |
+//! | [`insert`]                       | `[T;N]`, `T`                                      | `[T;N+1]`                             |
+//! | [`remove`]                       | `[T;N+1]`                                         | `[T;N]`, `T`                          |
+//! | [`filter`]                       | `[T;N]`, `&T -> bool`                             | `ArrayVec<T,N>`                       |
+//! | [`partition`]                    | `[T;N]`, `&T -> bool`                             | `ArrayVec<T,N>`, `ArrayVec<T,N>`      |
+//! | [`filter_map`]                   | `[T;N]`, `T -> Option<U>`                         | `ArrayVec<U,N>`                       |
+//! | [`dedup_by_key`]                 | `[T;N]`, `&T -> K`                                | `ArrayVec<T,N>`                       |
+//! | [`map`][boxed `map`]             | `Box<[T;N]>`, `T -> U`                            | `Box<[U;N]>`                          |
+//! | [`zip`][boxed `zip`]             | `Box<[T;N]>`, `Box<[U;N]>`                        | `Box<[(T,U);N]>`                      |
+//! | [`unzip`][boxed `unzip`]         | `Box<[(T,U);N]>`                                  | `Box<[T;N]>`, `Box<[U;N]>`            |
+//! | [`transpose`][boxed `transpose`] | `Box<[[T;M];N]>`                                  | `Box<[[T;N];M]>`                      |
+//! | [`into_iter`][boxed `into_iter`] | `Box<[T;N]>`                                      | `impl Iterator<Item = T>`             |
 //!
 //!
+//! [`raw`]: ./raw/index.html
 //! [`new`]: ./fn.new.html
+//! [`try_new`]: ./fn.try_new.html
+//! [`try_new_option`]: ./fn.try_new_option.html
+//! [`new_with_prefix`]: ./fn.new_with_prefix.html
+//! [`unfold`]: ./fn.unfold.html
+//! [`init_in_place`]: ./fn.init_in_place.html
+//! [`try_init_in_place`]: ./fn.try_init_in_place.html
+//! [`from_iter`]: ./fn.from_iter.html
+//! [`collect_array`]: ./trait.IteratorExt.html#method.collect_array
 //! [`new_boxed`]: ./fn.new_boxed.html
+//! [`try_new_boxed`]: ./fn.try_new_boxed.html
+//! [`new_boxed_zeroed`]: ./fn.new_boxed_zeroed.html
+//! [`new_in`]: ./fn.new_in.html
+//! [`map_in`]: ./fn.map_in.html
+//! [`new_rc`]: ./fn.new_rc.html
+//! [`try_new_rc`]: ./fn.try_new_rc.html
+//! [`new_arc`]: ./fn.new_arc.html
+//! [`try_new_arc`]: ./fn.try_new_arc.html
 //! [`into_iter`]: ./trait.Array.html#tymethod.into_iter
 //! [`map`]: ./trait.Array.html#tymethod.map
 //! [`zip`]: ./trait.Array.html#tymethod.zip
+//! [`zip_with`]: ./trait.Array.html#tymethod.zip_with
+//! [`zip3`]: ./trait.Array.html#tymethod.zip3
+//! [`enumerate`]: ./trait.Array.html#tymethod.enumerate
+//! [`map_indexed`]: ./trait.Array.html#tymethod.map_indexed
+//! [`try_map`]: ./trait.Array.html#tymethod.try_map
+//! [`try_zip_with`]: ./trait.Array.html#tymethod.try_zip_with
+//! [`each_ref`]: ./trait.Array.html#tymethod.each_ref
+//! [`each_mut`]: ./trait.Array.html#tymethod.each_mut
+//! [`map_ref`]: ./trait.Array.html#tymethod.map_ref
+//! [`map_mut`]: ./trait.Array.html#tymethod.map_mut
 //! [`unzip`]: ./trait.ArrayUnzip.html#tymethod.unzip
+//! [`zip_all`]: ./trait.ArrayZip.html#tymethod.zip_all
 //! [`transpose`]: ./trait.ArrayTranspose.html#tymethod.transpose
+//! [`transpose_option`]: ./trait.ArrayTransposeOption.html#tymethod.transpose_option
+//! [`transpose_result`]: ./trait.ArrayTransposeResult.html#tymethod.transpose_result
+//! [`transpose_array`]: ./trait.OptionArrayTranspose.html#tymethod.transpose_array
+//! [`flatten`]: ./trait.ArrayFlatten.html#tymethod.flatten
+//! [`unflatten`]: ./trait.Array.html#tymethod.unflatten
+//! [`concat`]: ./trait.Array.html#tymethod.concat
+//! [`split_at`]: ./trait.Array.html#tymethod.split_at
+//! [`push`]: ./trait.Array.html#tymethod.push
+//! [`pop`]: ./trait.Array.html#tymethod.pop
+//! [`split_first`]: ./trait.Array.html#tymethod.split_first
+//! [`split_last`]: ./trait.Array.html#tymethod.split_last
+//! [`insert`]: ./trait.Array.html#tymethod.insert
+//! [`remove`]: ./trait.Array.html#tymethod.remove
+//! [`filter`]: ./trait.Array.html#tymethod.filter
+//! [`partition`]: ./trait.Array.html#tymethod.partition
+//! [`filter_map`]: ./trait.Array.html#tymethod.filter_map
+//! [`dedup_by_key`]: ./trait.Array.html#tymethod.dedup_by_key
+//! [boxed `map`]: ./trait.BoxedArray.html#tymethod.map
+//! [boxed `zip`]: ./trait.BoxedArray.html#tymethod.zip
+//! [boxed `unzip`]: ./trait.BoxedArrayUnzip.html#tymethod.unzip
+//! [boxed `transpose`]: ./trait.BoxedArrayTranspose.html#tymethod.transpose
+//! [boxed `into_iter`]: ./trait.BoxedArray.html#tymethod.into_iter
 //! [Implementing matrices]: ./example/index.html
 
-use core::mem::MaybeUninit;
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+use core::mem::{ManuallyDrop, MaybeUninit};
+#[cfg(feature = "alloc")]
+use alloc::{boxed::Box, rc::Rc, vec::Vec};
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+use alloc::sync::Arc;
 
 mod utils;
 use crate::utils::*;
+pub mod raw;
 mod into_iter;
 pub use into_iter::IntoIter;
+mod array_vec;
+pub use array_vec::{ArrayVec, Drain, CapacityError};
+mod array_deque;
+pub use array_deque::ArrayDeque;
+mod from_iter;
+pub use from_iter::{PartialArray, FromIterError};
+#[cfg(feature = "alloc")]
+mod boxed;
+#[cfg(feature = "alloc")]
+pub use boxed::{BoxedIntoIter, NewBoxedError};
+mod zeroable;
+pub use zeroable::Zeroable;
 
 pub mod example;
 
@@ -163,16 +286,189 @@ pub mod example;
 /// assert_eq!(arr, [0, 1, 4, 9, 16]);
 /// ```
 pub fn new<T, const N: usize>(mut f: impl FnMut(usize) -> T) -> [T;N] {
-    let mut arr: [MaybeUninit<T>; N] = push_maybe_uninit(MaybeUninit::uninit());
-    for (i, item) in arr.iter_mut().enumerate() {
-        *item = MaybeUninit::new(f(i));
+    let mut arr = ArrayBuilder::new();
+    for i in 0..N {
+        arr.push(f(i));
+    }
+    arr.finish()
+}
+
+/// Takes a fallible closure and creates an array by calling that closure on each index.
+///
+/// Stops at the first error, dropping the elements that were already created.
+///
+/// # Example
+///
+/// Basic usage:
+///
+/// ```
+/// # use array_helpers::*;
+/// let strs = ["1", "2", "3"];
+/// let arr: Result<[u32; 3], _> = array_helpers::try_new(|i| strs[i].parse());
+/// assert_eq!(arr, Ok([1, 2, 3]));
+///
+/// let strs = ["1", "two", "3"];
+/// let arr: Result<[u32; 3], _> = array_helpers::try_new(|i| strs[i].parse());
+/// assert!(arr.is_err());
+/// ```
+pub fn try_new<T, E, const N: usize>(mut f: impl FnMut(usize) -> Result<T, E>) -> Result<[T;N], E> {
+    let mut arr = ArrayBuilder::new();
+    for i in 0..N {
+        arr.push(f(i)?);
+    }
+    Ok(arr.finish())
+}
+
+/// Takes a closure returning an `Option`, and creates an array by calling that closure on each index.
+///
+/// Stops at the first `None`, dropping the elements that were already created.
+///
+/// # Example
+///
+/// Basic usage:
+///
+/// ```
+/// # use array_helpers::*;
+/// let arr: Option<[usize; 4]> = array_helpers::try_new_option(|i| 12usize.checked_div(i + 1));
+/// assert_eq!(arr, Some([12, 6, 4, 3]));
+///
+/// let arr: Option<[usize; 4]> = array_helpers::try_new_option(|i| 12usize.checked_div(i));
+/// assert_eq!(arr, None);
+/// ```
+pub fn try_new_option<T, const N: usize>(mut f: impl FnMut(usize) -> Option<T>) -> Option<[T;N]> {
+    try_new(|i| f(i).ok_or(())).ok()
+}
+
+/// Takes a closure and creates an array by calling that closure on each index, along with the elements created so far.
+///
+/// # Example
+///
+/// Basic usage:
+///
+/// ```
+/// # use array_helpers::*;
+/// let fib: [u64; 8] = new_with_prefix(|i, prefix| if i < 2 { 1 } else { prefix[i - 1] + prefix[i - 2] });
+/// assert_eq!(fib, [1, 1, 2, 3, 5, 8, 13, 21]);
+///
+/// let sums: [u32; 4] = new_with_prefix(|i, prefix| prefix.last().unwrap_or(&0) + i as u32);
+/// assert_eq!(sums, [0, 1, 3, 6]);
+/// ```
+pub fn new_with_prefix<T, const N: usize>(mut f: impl FnMut(usize, &[T]) -> T) -> [T;N] {
+    let mut arr = ArrayBuilder::new();
+    for i in 0..N {
+        let item = f(i, arr.as_slice());
+        arr.push(item);
     }
-    unsafe {
-        pull_maybe_uninit(arr).assume_init()
+    arr.finish()
+}
+
+/// Creates an array by repeatedly calling a closure on a piece of state.
+///
+/// # Example
+///
+/// Basic usage:
+///
+/// ```
+/// # use array_helpers::*;
+/// let powers: [u32; 5] = unfold(1, |p| {
+///     let out = *p;
+///     *p *= 3;
+///     out
+/// });
+/// assert_eq!(powers, [1, 3, 9, 27, 81]);
+///
+/// let mut words = "the quick brown fox".split(' ');
+/// let arr: [String; 3] = unfold(&mut words, |w| w.next().unwrap().to_uppercase());
+/// assert_eq!(arr, ["THE", "QUICK", "BROWN"]);
+/// ```
+pub fn unfold<T, S, const N: usize>(mut state: S, mut f: impl FnMut(&mut S) -> T) -> [T;N] {
+    new(|_| f(&mut state))
+}
+
+/// Takes a closure and initializes an array in place by calling that closure on each index.
+///
+/// This is [`new`], for memory that already exists, such as a field of a large struct or a buffer from an arena.
+/// The array is never held on the stack.
+///
+/// If the closure panics, the elements that were already created are dropped, and the slot is left uninitialized.
+/// A `MaybeUninit` never drops its contents, so dropping the finished array is up to the caller.
+///
+/// # Example
+///
+/// Basic usage:
+///
+/// ```
+/// # use array_helpers::*;
+/// use std::mem::MaybeUninit;
+/// let mut slot = MaybeUninit::<[String; 3]>::uninit();
+/// let arr = init_in_place(&mut slot, |i| i.to_string());
+/// arr[0].push('!');
+/// assert_eq!(*arr, ["0!", "1", "2"]);
+/// # unsafe { slot.assume_init_drop() };
+/// ```
+///
+/// [`new`]: ./fn.new.html
+pub fn init_in_place<T, const N: usize>(slot: &mut MaybeUninit<[T;N]>, mut f: impl FnMut(usize) -> T) -> &mut [T;N] {
+    match try_init_slot::<T, core::convert::Infallible, N>(slot, |i| Ok(f(i))) {
+        Ok(arr) => arr,
+        Err(e) => match e {},
     }
 }
 
-#[cfg(feature = "std")]
+/// Takes a fallible closure and initializes an array in place by calling that closure on each index.
+///
+/// Stops at the first error, dropping the elements that were already created and leaving the slot uninitialized.
+///
+/// # Example
+///
+/// Basic usage:
+///
+/// ```
+/// # use array_helpers::*;
+/// use std::mem::MaybeUninit;
+/// let mut slot = MaybeUninit::<[u32; 3]>::uninit();
+/// assert_eq!(try_init_in_place(&mut slot, |i| if i < 2 { Ok(i as u32) } else { Err(i) }), Err(2));
+/// assert_eq!(try_init_in_place::<_, (), 3>(&mut slot, |i| Ok(i as u32)), Ok(&mut [0, 1, 2]));
+/// ```
+pub fn try_init_in_place<T, E, const N: usize>(slot: &mut MaybeUninit<[T;N]>, f: impl FnMut(usize) -> Result<T, E>) -> Result<&mut [T;N], E> {
+    try_init_slot(slot, f)
+}
+
+/// Creates an array from the items of an iterator.
+///
+/// Succeeds only if the iterator yields exactly `N` items.
+/// Otherwise, the error holds onto everything that was taken from the iterator.
+///
+/// # Example
+///
+/// Basic usage:
+///
+/// ```
+/// # use array_helpers::*;
+/// let arr: Result<[usize; 3], _> = array_helpers::from_iter(vec![1, 2, 3]);
+/// assert_eq!(arr.unwrap(), [1, 2, 3]);
+///
+/// let arr: Result<[usize; 3], _> = array_helpers::from_iter(vec![1, 2]);
+/// match arr {
+///     Err(FromIterError::TooFew(partial)) => assert_eq!(*partial, [1, 2]),
+///     _ => unreachable!(),
+/// }
+///
+/// let arr: Result<[usize; 3], _> = array_helpers::from_iter(vec![1, 2, 3, 4, 5]);
+/// match arr {
+///     Err(FromIterError::TooMany(arr, rest)) => {
+///         assert_eq!(arr, [1, 2, 3]);
+///         assert_eq!(rest.collect::<Vec<_>>(), [4, 5]);
+///     }
+///     _ => unreachable!(),
+/// }
+/// ```
+#[allow(clippy::type_complexity)]
+pub fn from_iter<I: IntoIterator, const N: usize>(iter: I) -> Result<[I::Item;N], FromIterError<I::Item, I::IntoIter, N>> {
+    from_iter::collect(iter)
+}
+
+#[cfg(feature = "alloc")]
 /// Takes a closure and creates an array by calling that closure on each index.
 ///
 /// The array is allocated directly on the heap.
@@ -188,17 +484,215 @@ pub fn new<T, const N: usize>(mut f: impl FnMut(usize) -> T) -> [T;N] {
 /// assert_eq!(arr[9_999_999], 99_999_980_000_001);
 /// ```
 pub fn new_boxed<T, const N: usize>(mut f: impl FnMut(usize) -> T) -> Box<[T;N]> {
-    unsafe {
-        let mut v: Vec<MaybeUninit<T>> = Vec::with_capacity(N);
-        v.set_len(N);
-        for i in 0..N {
-            v[i] = MaybeUninit::new(f(i));
-        }
-        let raw_slice = Box::into_raw(v.into_boxed_slice());
-        Box::from_raw(raw_slice as *mut [T; N])
+    // If `f` panics, the vector drops the elements created so far.
+    let mut v = Vec::with_capacity(N);
+    for i in 0..N {
+        v.push(f(i));
+    }
+    boxed::from_vec(v)
+}
+
+#[cfg(feature = "alloc")]
+/// Takes a fallible closure and creates an array on the heap by calling that closure on each index.
+///
+/// Unlike [`new_boxed`], this reports allocation failure instead of aborting.
+/// Stops at the first error, dropping the elements that were already created.
+///
+/// # Example
+///
+/// Basic usage:
+///
+/// ```
+/// # use array_helpers::*;
+/// let arr: Result<Box<[u64; 1000]>, NewBoxedError<()>> = try_new_boxed(|n| Ok(n as u64));
+/// assert_eq!(arr.unwrap()[999], 999);
+///
+/// let arr: Result<Box<[u64; 1000]>, _> = try_new_boxed(|n| if n < 500 { Ok(n as u64) } else { Err(n) });
+/// assert_eq!(arr, Err(NewBoxedError::Closure(500)));
+///
+/// // 64 TiB, more than a typical allocator will hand out.
+/// # #[cfg(target_pointer_width = "64")] {
+/// let arr: Result<Box<[u64; 1 << 43]>, NewBoxedError<()>> = try_new_boxed(|_| Ok(0));
+/// assert!(matches!(arr, Err(NewBoxedError::Alloc(_))));
+/// # }
+/// ```
+///
+/// [`new_boxed`]: ./fn.new_boxed.html
+pub fn try_new_boxed<T, E, const N: usize>(mut f: impl FnMut(usize) -> Result<T, E>) -> Result<Box<[T;N]>, NewBoxedError<E>> {
+    let mut v = Vec::new();
+    v.try_reserve_exact(N).map_err(NewBoxedError::Alloc)?;
+    for i in 0..N {
+        v.push(f(i).map_err(NewBoxedError::Closure)?);
+    }
+    Ok(boxed::from_vec(v))
+}
+
+#[cfg(feature = "alloc")]
+/// Creates an array of zeroes on the heap.
+///
+/// This asks the allocator for zeroed memory, which is often much cheaper than writing every element.
+///
+/// # Example
+///
+/// Basic usage:
+///
+/// ```
+/// # use array_helpers::*;
+/// // 8 MB, too big to pass through a typical thread's stack.
+/// let arr: Box<[u64; 1 << 20]> = new_boxed_zeroed();
+/// assert_eq!(arr[(1 << 20) - 1], 0);
+/// ```
+pub fn new_boxed_zeroed<T: Zeroable, const N: usize>() -> Box<[T;N]> {
+    boxed::zeroed()
+}
+
+#[cfg(feature = "allocator_api")]
+/// Takes a closure and creates an array in the given allocator by calling that closure on each index.
+///
+/// This is [`new_boxed`], for custom allocators.
+///
+/// # Example
+///
+/// Basic usage:
+///
+/// ```
+/// #![feature(allocator_api)]
+/// # use array_helpers::*;
+/// use std::alloc::System;
+/// let arr: Box<[u64; 10_000_000], System> = new_in(System, |n| n as u64);
+/// assert_eq!(arr[9_999_999], 9_999_999);
+/// ```
+///
+/// [`new_boxed`]: ./fn.new_boxed.html
+pub fn new_in<T, A: core::alloc::Allocator, const N: usize>(alloc: A, mut f: impl FnMut(usize) -> T) -> Box<[T;N], A> {
+    let mut arr = Box::<[T;N], A>::new_uninit_in(alloc);
+    match try_init_slot::<T, core::convert::Infallible, N>(&mut arr, |i| Ok(f(i))) {
+        Ok(_) => {}
+        Err(e) => match e {},
+    }
+    unsafe { arr.assume_init() }
+}
+
+#[cfg(feature = "allocator_api")]
+/// Maps a boxed array into a new allocation from the given allocator.
+///
+/// The input array is freed by its own allocator. Like [`new_in`], this never puts the whole array on the stack.
+///
+/// # Example
+///
+/// Basic usage:
+///
+/// ```
+/// #![feature(allocator_api)]
+/// # use array_helpers::*;
+/// use std::alloc::{Global, System};
+/// let arr: Box<[u32; 3], Global> = new_in(Global, |n| n as u32);
+/// let arr: Box<[String; 3], System> = map_in(arr, System, |n| n.to_string());
+/// assert_eq!(*arr, ["0", "1", "2"]);
+/// ```
+///
+/// [`new_in`]: ./fn.new_in.html
+pub fn map_in<T, U, A: core::alloc::Allocator, B: core::alloc::Allocator, const N: usize>(arr: Box<[T;N], A>, alloc: B, f: impl FnMut(T) -> U) -> Box<[U;N], B> {
+    boxed::map_in(arr, alloc, f)
+}
+
+#[cfg(feature = "alloc")]
+/// Takes a closure and creates a reference-counted array by calling that closure on each index.
+///
+/// The elements are written directly into the `Rc`'s allocation, so the array is never copied.
+///
+/// # Example
+///
+/// Basic usage:
+///
+/// ```
+/// # use array_helpers::*;
+/// # use std::rc::Rc;
+/// let arr: Rc<[u64; 100_000]> = new_rc(|n| n as u64);
+/// let shared = Rc::clone(&arr);
+/// assert_eq!(shared[99_999], 99_999);
+/// ```
+pub fn new_rc<T, const N: usize>(mut f: impl FnMut(usize) -> T) -> Rc<[T;N]> {
+    match try_new_rc::<T, core::convert::Infallible, N>(|i| Ok(f(i))) {
+        Ok(arr) => arr,
+        Err(e) => match e {},
+    }
+}
+
+#[cfg(feature = "alloc")]
+/// Takes a fallible closure and creates a reference-counted array by calling that closure on each index.
+///
+/// Stops at the first error, dropping the elements that were already created.
+///
+/// # Example
+///
+/// Basic usage:
+///
+/// ```
+/// # use array_helpers::*;
+/// # use std::rc::Rc;
+/// let arr: Result<Rc<[u64; 3]>, ()> = try_new_rc(|n| Ok(n as u64));
+/// assert_eq!(*arr.unwrap(), [0, 1, 2]);
+///
+/// let arr: Result<Rc<[u64; 3]>, usize> = try_new_rc(|n| if n < 2 { Ok(n as u64) } else { Err(n) });
+/// assert_eq!(arr, Err(2));
+/// ```
+pub fn try_new_rc<T, E, const N: usize>(f: impl FnMut(usize) -> Result<T, E>) -> Result<Rc<[T;N]>, E> {
+    let mut arr = Rc::<[T;N]>::new_uninit();
+    // The `Rc` was just created, so it is not shared.
+    try_init_slot(Rc::get_mut(&mut arr).unwrap(), f)?;
+    Ok(unsafe { arr.assume_init() })
+}
+
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+/// Takes a closure and creates an atomically reference-counted array by calling that closure on each index.
+///
+/// The elements are written directly into the `Arc`'s allocation, so the array is never copied.
+///
+/// # Example
+///
+/// Basic usage:
+///
+/// ```
+/// # use array_helpers::*;
+/// # use std::sync::Arc;
+/// let table: Arc<[u64; 100_000]> = new_arc(|n| (n as u64) * (n as u64));
+/// let shared = Arc::clone(&table);
+/// let handle = std::thread::spawn(move || shared[99_999]);
+/// assert_eq!(handle.join().unwrap(), 9_999_800_001);
+/// ```
+pub fn new_arc<T, const N: usize>(mut f: impl FnMut(usize) -> T) -> Arc<[T;N]> {
+    match try_new_arc::<T, core::convert::Infallible, N>(|i| Ok(f(i))) {
+        Ok(arr) => arr,
+        Err(e) => match e {},
     }
 }
 
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+/// Takes a fallible closure and creates an atomically reference-counted array by calling that closure on each index.
+///
+/// Stops at the first error, dropping the elements that were already created.
+///
+/// # Example
+///
+/// Basic usage:
+///
+/// ```
+/// # use array_helpers::*;
+/// # use std::sync::Arc;
+/// let arr: Result<Arc<[u64; 3]>, ()> = try_new_arc(|n| Ok(n as u64));
+/// assert_eq!(*arr.unwrap(), [0, 1, 2]);
+///
+/// let arr: Result<Arc<[u64; 3]>, usize> = try_new_arc(|n| if n < 2 { Ok(n as u64) } else { Err(n) });
+/// assert_eq!(arr, Err(2));
+/// ```
+pub fn try_new_arc<T, E, const N: usize>(f: impl FnMut(usize) -> Result<T, E>) -> Result<Arc<[T;N]>, E> {
+    let mut arr = Arc::<[T;N]>::new_uninit();
+    // The `Arc` was just created, so it is not shared.
+    try_init_slot(Arc::get_mut(&mut arr).unwrap(), f)?;
+    Ok(unsafe { arr.assume_init() })
+}
+
 
 
 
@@ -207,16 +701,35 @@ pub fn new_boxed<T, const N: usize>(mut f: impl FnMut(usize) -> T) -> Box<[T;N]>
 /// These methods cannot be attached directly to the type `[T;N]`.
 /// Trying to do so results in error [E0118].
 ///
-/// [`Array<T, {N}>`] is implemented for, and only for, the type `[T;N]`.
+/// [`Array<T, N>`] is implemented for, and only for, the type `[T;N]`.
+///
+/// Some of these methods share a name with a slice method, such as `split_at`.
+/// Since they take the array by value, they take precedence.
+/// To call the slice method instead, write `arr[..].split_at(k)`.
+///
+/// Some other names collide with the standard library, so those methods are best called in the form `Array::method(arr, ...)`.
+/// `map`, `each_ref` and `each_mut` are shadowed by inherent methods on arrays,
+/// so `arr.map(f)` calls the standard library's version rather than this crate's.
+/// `into_iter` is ambiguous with `IntoIterator::into_iter` from Rust 2021 on,
+/// and `try_map` collides with an unstable inherent method on arrays.
+///
+/// Methods that change the length of an array take the new length as an extra parameter,
+/// because stable Rust cannot yet write `[T; N + 1]`.
+/// It is usually inferred. If it is wrong, the program fails to compile.
 ///
 /// [E0118]: https://doc.rust-lang.org/error-index.html#E0118
-/// [`Array<T, {N}>`]: ./trait.Array.html
+/// [`Array<T, N>`]: ./trait.Array.html
 pub trait Array<T, const N: usize>: private::ArraySealed where
     // Self == [T;N],
 {
 
     /// Creates an iterator from an array.
     ///
+    /// From Rust 2021 on, `arr.into_iter()` also finds the standard library's `IntoIterator` for arrays,
+    /// so when this trait is in scope that call is ambiguous, and fails to compile with error E0034.
+    /// Call this method as `Array::into_iter(arr)` instead.
+    /// Its iterator, [`IntoIter`], can hand back the remaining elements as an array or an [`ArrayVec`].
+    ///
     /// # Example
     ///
     /// Basic usage:
@@ -224,23 +737,29 @@ pub trait Array<T, const N: usize>: private::ArraySealed where
     /// ```
     /// # use array_helpers::*;
     /// let arr: [usize; 4] = [1, 2, 3, 4];
-    /// let mut iter = arr.into_iter();
+    /// let mut iter = Array::into_iter(arr);
     /// assert_eq!(Some(1), iter.next());
     /// assert_eq!(Some(2), iter.next());
     /// assert_eq!(Some(3), iter.next());
     /// assert_eq!(Some(4), iter.next());
     /// assert_eq!(None, iter.next());
     /// ```
-    fn into_iter(self) -> IntoIter<T, {N}>;
+    ///
+    /// [`IntoIter`]: ./struct.IntoIter.html
+    /// [`ArrayVec`]: ./struct.ArrayVec.html
+    fn into_iter(self) -> IntoIter<T, N>;
     /// Takes a closure and creates a new array by calling that closure on each element.
     ///
+    /// The standard library's inherent `[T;N]::map` takes precedence in method-call syntax,
+    /// so call this one as `Array::map(arr, f)`.
+    ///
     /// # Example
     ///
     /// Basic usage:
     ///
     /// ```
     /// # use array_helpers::*;
-    /// assert_eq!([1,2,3,4].map(|x| x * x), [1, 4, 9, 16]);
+    /// assert_eq!(Array::map([1,2,3,4], |x| x * x), [1, 4, 9, 16]);
     /// ```
     fn map<U>(self, f: impl FnMut(T) -> U) -> [U;N];
     /// Converts a pair of arrays into an array of pairs.
@@ -256,41 +775,9 @@ pub trait Array<T, const N: usize>: private::ArraySealed where
     /// assert_eq!(arr1.zip(arr2), [(1, 'a'), (2, 'b'), (3, 'c'), (4, 'd')]);
     /// ```
     fn zip<U>(self, other: [U;N]) -> [(T,U); N];
-}
-
-impl<T, const N: usize> Array<T, {N}> for [T;N] {
-    fn into_iter(self) -> IntoIter<T, {N}> {
-        into_iter::new(self)
-    }
-    fn map<U>(self, mut f: impl FnMut(T) -> U) -> [U;N] {
-        let mut arr = push_maybe_uninit(MaybeUninit::new(self));
-        unsafe {
-            new(|i| f(extract(&mut arr, i)))
-        }
-    }
-    fn zip<U>(self, other: [U;N]) -> [(T,U); N] {
-        let mut arr1 = push_maybe_uninit(MaybeUninit::new(self));
-        let mut arr2 = push_maybe_uninit(MaybeUninit::new(other));
-        unsafe {
-            new(|i| (extract(&mut arr1, i), extract(&mut arr2, i)))
-        }
-    }
-}
-
-
-/// A trait that exists for the sole purpose of allowing the unzip method to be put on arrays.
-///
-/// This method cannot be attached directly to the type `[(T,U);N]`.
-/// Trying to do so results in error [E0118].
-///
-/// [`ArrayUnzip<T, U, {N}>`] is implemented for, and only for, the type `[(T,U);N]`.
-///
-/// [E0118]: https://doc.rust-lang.org/error-index.html#E0118
-/// [`ArrayUnzip<T, U, {N}>`]: ./trait.ArrayUnzip.html
-pub trait ArrayUnzip<T, U, const N: usize>: private::ArrayUnzipSealed where
-    // Self == [(T,U);N],
-{
-    /// Converts an array of pairs into a pair of arrays.
+    /// Takes a closure and creates a new array by calling that closure on each pair of elements.
+    ///
+    /// This is like [`zip`] followed by [`map`], but without the intermediate array of pairs.
     ///
     /// # Example
     ///
@@ -298,41 +785,17 @@ pub trait ArrayUnzip<T, U, const N: usize>: private::ArrayUnzipSealed where
     ///
     /// ```
     /// # use array_helpers::*;
-    /// let arr = [(1, 'a'), (2, 'b'), (3, 'c'), (4, 'd')];
-    /// assert_eq!(arr.unzip(), ([1,2,3,4], ['a','b','c','d']));
+    /// let arr1 = [1, 2, 3];
+    /// let arr2 = [10, 20, 30];
+    /// assert_eq!(arr1.zip_with(arr2, |a, b| a + b), [11, 22, 33]);
     /// ```
-    fn unzip(self) -> ([T;N],[U;N]);
-}
-
-impl<T, U, const N: usize> ArrayUnzip<T,U,{N}> for [(T,U);N] {
-    fn unzip(self) -> ([T;N],[U;N]) {
-        let mut arr = push_maybe_uninit(MaybeUninit::new(self));
-        let mut out1: [MaybeUninit<T>; N] = push_maybe_uninit(MaybeUninit::uninit());
-        let mut out2: [MaybeUninit<U>; N] = push_maybe_uninit(MaybeUninit::uninit());
-        unsafe {
-            for i in 0..N {
-                let (t,u) = extract(&mut arr, i);
-                out1[i] = MaybeUninit::new(t);
-                out2[i] = MaybeUninit::new(u);
-            }
-            (pull_maybe_uninit(out1).assume_init(), pull_maybe_uninit(out2).assume_init())
-        }
-    }
-}
-
-/// A trait that exists for the sole purpose of allowing the transpose method to be put on arrays.
-///
-/// This method cannot be attached directly to the type `[[T;M];N]`.
-/// Trying to do so results in error [E0118].
-///
-/// [`ArrayUnzip<T, {M}, {N}>`] is implemented for, and only for, the type `[[T;M];N]`.
-///
-/// [E0118]: https://doc.rust-lang.org/error-index.html#E0118
-/// [`ArrayUnzip<T, {M}, {N}>`]: ./trait.ArrayUnzip.html
-pub trait ArrayTranspose<T, const M: usize, const N: usize>: private::ArrayTransposeSealed where
-    // Self == [[T;M];N],
-{
-    /// Transposes a 2D array.
+    ///
+    /// [`zip`]: #tymethod.zip
+    /// [`map`]: #tymethod.map
+    fn zip_with<U, V>(self, other: [U;N], f: impl FnMut(T, U) -> V) -> [V;N];
+    /// Converts three arrays into an array of triples.
+    ///
+    /// For more arrays, see [`zip_all`].
     ///
     /// # Example
     ///
@@ -340,31 +803,1058 @@ pub trait ArrayTranspose<T, const M: usize, const N: usize>: private::ArrayTrans
     ///
     /// ```
     /// # use array_helpers::*;
-    /// let arr = [[1,2,3],[4,5,6]];
-    /// assert_eq!(arr.transpose(), [[1, 4], [2, 5], [3, 6]]);
+    /// let arr1 = [1, 2];
+    /// let arr2 = ['a', 'b'];
+    /// let arr3 = ["x", "y"];
+    /// assert_eq!(arr1.zip3(arr2, arr3), [(1, 'a', "x"), (2, 'b', "y")]);
     /// ```
-    fn transpose(self) -> [[T;N];M];
-}
-
-impl<T, const M: usize, const N: usize> ArrayTranspose<T, {M}, {N}> for [[T;M];N] {
-    fn transpose(self) -> [[T;N];M] {
-        let mut arr = push_maybe_uninit(MaybeUninit::new(self)).map(push_maybe_uninit);
-        unsafe {
-            new(|i| new(|j| extract(&mut arr[j], i)))
-        }
-    }
-}
-
-/// A private module, whose traits cannot be implemented by users of the crate.
+    ///
+    /// [`zip_all`]: ./trait.ArrayZip.html#tymethod.zip_all
+    fn zip3<U, V>(self, other1: [U;N], other2: [V;N]) -> [(T,U,V); N];
+    /// Pairs each element with its index.
+    ///
+    /// # Example
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use array_helpers::*;
+    /// assert_eq!(['a', 'b', 'c'].enumerate(), [(0, 'a'), (1, 'b'), (2, 'c')]);
+    /// ```
+    fn enumerate(self) -> [(usize, T); N];
+    /// Takes a closure and creates a new array by calling that closure on each index and element.
+    ///
+    /// # Example
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use array_helpers::*;
+    /// assert_eq!([5, 5, 5].map_indexed(|i, x| i * x), [0, 5, 10]);
+    /// ```
+    fn map_indexed<U>(self, f: impl FnMut(usize, T) -> U) -> [U;N];
+    /// Takes a fallible closure and creates a new array by calling that closure on each element.
+    ///
+    /// Stops at the first error, dropping both the elements already produced and the elements not yet consumed.
+    ///
+    /// The standard library has an unstable inherent method `[T;N]::try_map`, with a different, `Try`-based signature.
+    /// Method-call syntax (`arr.try_map(f)`) triggers the `unstable_name_collisions` warning,
+    /// and will silently switch to the standard library's method once it is stabilized.
+    /// Call this one as `Array::try_map(arr, f)` instead.
+    ///
+    /// # Example
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use array_helpers::*;
+    /// let arr = ["1", "2", "3"];
+    /// assert_eq!(Array::try_map(arr, |s| s.parse::<u32>()), Ok([1, 2, 3]));
+    ///
+    /// let arr = ["1", "two", "3"];
+    /// assert!(Array::try_map(arr, |s| s.parse::<u32>()).is_err());
+    /// ```
+    fn try_map<U, E>(self, f: impl FnMut(T) -> Result<U, E>) -> Result<[U;N], E>;
+    /// Takes a fallible closure and creates a new array by calling that closure on each pair of elements.
+    ///
+    /// Stops at the first error, dropping both the elements already produced and the elements not yet consumed.
+    ///
+    /// # Example
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use array_helpers::*;
+    /// let arr1: [u8; 3] = [1, 2, 3];
+    /// let arr2: [u8; 3] = [10, 20, 30];
+    /// assert_eq!(arr1.try_zip_with(arr2, |a, b| a.checked_mul(b).ok_or(())), Ok([10, 40, 90]));
+    ///
+    /// let arr2: [u8; 3] = [10, 200, 30];
+    /// assert_eq!(arr1.try_zip_with(arr2, |a, b| a.checked_mul(b).ok_or(())), Err(()));
+    /// ```
+    fn try_zip_with<U, V, E>(self, other: [U;N], f: impl FnMut(T, U) -> Result<V, E>) -> Result<[V;N], E>;
+    /// Borrows each element of an array.
+    ///
+    /// The standard library's inherent `[T;N]::each_ref` takes precedence in method-call syntax,
+    /// so call this one as `Array::each_ref(&arr)`.
+    ///
+    /// # Example
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use array_helpers::*;
+    /// let arr = [String::from("a"), String::from("bc")];
+    /// let refs: [&String; 2] = Array::each_ref(&arr);
+    /// assert_eq!(refs, [&"a", &"bc"]);
+    /// ```
+    fn each_ref(&self) -> [&T;N];
+    /// Mutably borrows each element of an array.
+    ///
+    /// The standard library's inherent `[T;N]::each_mut` takes precedence in method-call syntax,
+    /// so call this one as `Array::each_mut(&mut arr)`.
+    ///
+    /// # Example
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use array_helpers::*;
+    /// let mut arr = [1, 2, 3];
+    /// let [a, _, c] = Array::each_mut(&mut arr);
+    /// std::mem::swap(a, c);
+    /// assert_eq!(arr, [3, 2, 1]);
+    /// ```
+    fn each_mut(&mut self) -> [&mut T;N];
+    /// Takes a closure and creates a new array by calling that closure on a reference to each element.
+    ///
+    /// # Example
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use array_helpers::*;
+    /// let arr = [String::from("a"), String::from("bc")];
+    /// assert_eq!(arr.map_ref(|s| s.len()), [1, 2]);
+    /// assert_eq!(arr, ["a", "bc"]);
+    /// ```
+    fn map_ref<'a, U>(&'a self, f: impl FnMut(&'a T) -> U) -> [U;N] where T: 'a;
+    /// Takes a closure and creates a new array by calling that closure on a mutable reference to each element.
+    ///
+    /// # Example
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use array_helpers::*;
+    /// let mut arr = [vec![1, 2], vec![3]];
+    /// assert_eq!(arr.map_mut(|v| v.pop()), [Some(2), Some(3)]);
+    /// assert_eq!(arr, [vec![1], vec![]]);
+    /// ```
+    fn map_mut<'a, U>(&'a mut self, f: impl FnMut(&'a mut T) -> U) -> [U;N] where T: 'a;
+    /// Splits an array into `L` rows of length `K`.
+    ///
+    /// This is the inverse of [`flatten`]. It does not move any elements; it only reinterprets the array.
+    /// If `K * L` is not equal to `N`, the program fails to compile.
+    ///
+    /// # Example
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use array_helpers::*;
+    /// let arr = [1, 2, 3, 4, 5, 6];
+    /// assert_eq!(arr.unflatten::<3, 2>(), [[1, 2, 3], [4, 5, 6]]);
+    ///
+    /// let rows: [[usize; 2]; 3] = arr.unflatten();
+    /// assert_eq!(rows, [[1, 2], [3, 4], [5, 6]]);
+    /// ```
+    ///
+    /// The length must be divisible by the row length:
+    ///
+    /// ```compile_fail
+    /// # use array_helpers::*;
+    /// let arr = [1, 2, 3, 4, 5, 6];
+    /// let rows: [[usize; 4]; 1] = arr.unflatten();
+    /// ```
+    ///
+    /// [`flatten`]: ./trait.ArrayFlatten.html#tymethod.flatten
+    fn unflatten<const K: usize, const L: usize>(self) -> [[T;K];L];
+    /// Views an array as `L` rows of length `K`.
+    ///
+    /// # Example
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use array_helpers::*;
+    /// let arr = [1, 2, 3, 4, 5, 6];
+    /// assert_eq!(arr.unflatten_ref::<2, 3>(), &[[1, 2], [3, 4], [5, 6]]);
+    /// ```
+    fn unflatten_ref<const K: usize, const L: usize>(&self) -> &[[T;K];L];
+    /// Mutably views an array as `L` rows of length `K`.
+    ///
+    /// # Example
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use array_helpers::*;
+    /// let mut arr = [1, 2, 3, 4, 5, 6];
+    /// arr.unflatten_mut::<2, 3>()[1] = [30, 40];
+    /// assert_eq!(arr, [1, 2, 30, 40, 5, 6]);
+    /// ```
+    fn unflatten_mut<const K: usize, const L: usize>(&mut self) -> &mut [[T;K];L];
+    /// Joins two arrays end to end.
+    ///
+    /// # Example
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use array_helpers::*;
+    /// let arr: [usize; 5] = [1, 2].concat([3, 4, 5]);
+    /// assert_eq!(arr, [1, 2, 3, 4, 5]);
+    /// ```
+    ///
+    /// The length must be correct:
+    ///
+    /// ```compile_fail
+    /// # use array_helpers::*;
+    /// let arr: [usize; 4] = [1, 2].concat([3, 4, 5]);
+    /// ```
+    fn concat<const M: usize, const L: usize>(self, other: [T;M]) -> [T;L];
+    /// Splits an array into its first `K` elements and the rest.
+    ///
+    /// # Example
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use array_helpers::*;
+    /// let (a, b) = [1, 2, 3, 4, 5].split_at::<2, 3>();
+    /// assert_eq!(a, [1, 2]);
+    /// assert_eq!(b, [3, 4, 5]);
+    /// ```
+    fn split_at<const K: usize, const R: usize>(self) -> ([T;K], [T;R]);
+    /// Adds an element to the end of an array.
+    ///
+    /// # Example
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use array_helpers::*;
+    /// let arr: [usize; 4] = [1, 2, 3].push(4);
+    /// assert_eq!(arr, [1, 2, 3, 4]);
+    /// ```
+    fn push<const L: usize>(self, item: T) -> [T;L];
+    /// Removes the last element of an array.
+    ///
+    /// This is the same as [`split_last`].
+    ///
+    /// # Example
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use array_helpers::*;
+    /// let (arr, last): ([usize; 3], usize) = [1, 2, 3, 4].pop();
+    /// assert_eq!(arr, [1, 2, 3]);
+    /// assert_eq!(last, 4);
+    /// ```
+    ///
+    /// [`split_last`]: #tymethod.split_last
+    fn pop<const L: usize>(self) -> ([T;L], T);
+    /// Splits off the first element of an array.
+    ///
+    /// # Example
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use array_helpers::*;
+    /// let (first, rest): (usize, [usize; 3]) = [1, 2, 3, 4].split_first();
+    /// assert_eq!(first, 1);
+    /// assert_eq!(rest, [2, 3, 4]);
+    /// ```
+    fn split_first<const L: usize>(self) -> (T, [T;L]);
+    /// Splits off the last element of an array.
+    ///
+    /// # Example
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use array_helpers::*;
+    /// let (rest, last): ([usize; 3], usize) = [1, 2, 3, 4].split_last();
+    /// assert_eq!(rest, [1, 2, 3]);
+    /// assert_eq!(last, 4);
+    /// ```
+    fn split_last<const L: usize>(self) -> ([T;L], T);
+    /// Inserts an element at index `I`, shifting the later elements back.
+    ///
+    /// # Example
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use array_helpers::*;
+    /// assert_eq!([1, 2, 4].insert::<2, 4>(3), [1, 2, 3, 4]);
+    /// ```
+    ///
+    /// The index must be in bounds:
+    ///
+    /// ```compile_fail
+    /// # use array_helpers::*;
+    /// let arr = [1, 2, 4].insert::<4, 4>(3);
+    /// ```
+    fn insert<const I: usize, const L: usize>(self, item: T) -> [T;L];
+    /// Removes the element at index `I`, shifting the later elements forward.
+    ///
+    /// # Example
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use array_helpers::*;
+    /// assert_eq!([1, 2, 3, 4].remove::<1, 3>(), ([1, 3, 4], 2));
+    /// ```
+    ///
+    /// The index must be in bounds:
+    ///
+    /// ```compile_fail
+    /// # use array_helpers::*;
+    /// let arr = [1, 2, 3, 4].remove::<4, 3>();
+    /// ```
+    fn remove<const I: usize, const L: usize>(self) -> ([T;L], T);
+    /// Keeps the elements for which `pred` returns `true`, in order.
+    ///
+    /// # Example
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use array_helpers::*;
+    /// let evens = [1, 2, 3, 4, 5, 6].filter(|x| x % 2 == 0);
+    /// assert_eq!(*evens, [2, 4, 6]);
+    /// ```
+    fn filter(self, pred: impl FnMut(&T) -> bool) -> ArrayVec<T, N>;
+    /// Splits the elements into those for which `pred` returns `true`, and those for which it returns `false`.
+    ///
+    /// Both halves keep their original order.
+    ///
+    /// # Example
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use array_helpers::*;
+    /// let (evens, odds) = [1, 2, 3, 4, 5, 6].partition(|x| x % 2 == 0);
+    /// assert_eq!((&*evens, &*odds), (&[2, 4, 6][..], &[1, 3, 5][..]));
+    /// ```
+    fn partition(self, pred: impl FnMut(&T) -> bool) -> (ArrayVec<T, N>, ArrayVec<T, N>);
+    /// Maps each element, keeping the results that are `Some`, in order.
+    ///
+    /// # Example
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use array_helpers::*;
+    /// let numbers = ["1", "two", "3"].filter_map(|s| s.parse::<u32>().ok());
+    /// assert_eq!(*numbers, [1, 3]);
+    /// ```
+    fn filter_map<U>(self, f: impl FnMut(T) -> Option<U>) -> ArrayVec<U, N>;
+    /// Removes consecutive elements that map to the same key, keeping the first of each run.
+    ///
+    /// # Example
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use array_helpers::*;
+    /// let runs = [10, 11, 20, 21, 22, 10].dedup_by_key(|x| x / 10);
+    /// assert_eq!(*runs, [10, 20, 10]);
+    /// ```
+    fn dedup_by_key<K: PartialEq>(self, key: impl FnMut(&T) -> K) -> ArrayVec<T, N>;
+}
+
+impl<T, const N: usize> Array<T, N> for [T;N] {
+    fn into_iter(self) -> IntoIter<T, N> {
+        into_iter::new(self)
+    }
+    fn map<U>(self, mut f: impl FnMut(T) -> U) -> [U;N] {
+        // If `f` panics, the iterator drops the unconsumed inputs, and the builder drops the finished outputs.
+        let mut out = ArrayBuilder::new();
+        for item in Array::into_iter(self) {
+            out.push(f(item));
+        }
+        out.finish()
+    }
+    fn zip<U>(self, other: [U;N]) -> [(T,U); N] {
+        self.zip_with(other, |t, u| (t, u))
+    }
+    fn zip_with<U, V>(self, other: [U;N], mut f: impl FnMut(T, U) -> V) -> [V;N] {
+        let mut out = ArrayBuilder::new();
+        for (t, u) in Array::into_iter(self).zip(Array::into_iter(other)) {
+            out.push(f(t, u));
+        }
+        out.finish()
+    }
+    fn zip3<U, V>(self, other1: [U;N], other2: [V;N]) -> [(T,U,V); N] {
+        (self, other1, other2).zip_all()
+    }
+    fn enumerate(self) -> [(usize, T); N] {
+        self.map_indexed(|i, t| (i, t))
+    }
+    fn map_indexed<U>(self, mut f: impl FnMut(usize, T) -> U) -> [U;N] {
+        let mut out = ArrayBuilder::new();
+        for (i, item) in Array::into_iter(self).enumerate() {
+            out.push(f(i, item));
+        }
+        out.finish()
+    }
+    fn try_map<U, E>(self, mut f: impl FnMut(T) -> Result<U, E>) -> Result<[U;N], E> {
+        let mut out = ArrayBuilder::new();
+        for item in Array::into_iter(self) {
+            out.push(f(item)?);
+        }
+        Ok(out.finish())
+    }
+    fn try_zip_with<U, V, E>(self, other: [U;N], mut f: impl FnMut(T, U) -> Result<V, E>) -> Result<[V;N], E> {
+        let mut out = ArrayBuilder::new();
+        for (t, u) in Array::into_iter(self).zip(Array::into_iter(other)) {
+            out.push(f(t, u)?);
+        }
+        Ok(out.finish())
+    }
+    fn each_ref(&self) -> [&T;N] {
+        let mut iter = self.iter();
+        new(|_| iter.next().unwrap())
+    }
+    fn each_mut(&mut self) -> [&mut T;N] {
+        let mut iter = self.iter_mut();
+        new(|_| iter.next().unwrap())
+    }
+    fn map_ref<'a, U>(&'a self, f: impl FnMut(&'a T) -> U) -> [U;N] where T: 'a {
+        Array::map(Array::each_ref(self), f)
+    }
+    fn map_mut<'a, U>(&'a mut self, f: impl FnMut(&'a mut T) -> U) -> [U;N] where T: 'a {
+        Array::map(Array::each_mut(self), f)
+    }
+    fn unflatten<const K: usize, const L: usize>(self) -> [[T;K];L] {
+        let () = Lengths::<K, L, N>::PRODUCT;
+        unsafe { unsafe_transmute(self) }
+    }
+    fn unflatten_ref<const K: usize, const L: usize>(&self) -> &[[T;K];L] {
+        let () = Lengths::<K, L, N>::PRODUCT;
+        unsafe { &*(self as *const [T;N] as *const [[T;K];L]) }
+    }
+    fn unflatten_mut<const K: usize, const L: usize>(&mut self) -> &mut [[T;K];L] {
+        let () = Lengths::<K, L, N>::PRODUCT;
+        unsafe { &mut *(self as *mut [T;N] as *mut [[T;K];L]) }
+    }
+    fn concat<const M: usize, const L: usize>(self, other: [T;M]) -> [T;L] {
+        let () = Lengths::<N, M, L>::SUM;
+        let (this, other) = (ManuallyDrop::new(self), ManuallyDrop::new(other));
+        let mut out = MaybeUninit::<[T;L]>::uninit();
+        unsafe {
+            let dst = out.as_mut_ptr() as *mut T;
+            core::ptr::copy_nonoverlapping(this.as_ptr(), dst, N);
+            core::ptr::copy_nonoverlapping(other.as_ptr(), dst.add(N), M);
+            out.assume_init()
+        }
+    }
+    fn split_at<const K: usize, const R: usize>(self) -> ([T;K], [T;R]) {
+        let () = Lengths::<K, R, N>::SUM;
+        let this = ManuallyDrop::new(self);
+        let mut left = MaybeUninit::<[T;K]>::uninit();
+        let mut right = MaybeUninit::<[T;R]>::uninit();
+        unsafe {
+            core::ptr::copy_nonoverlapping(this.as_ptr(), left.as_mut_ptr() as *mut T, K);
+            core::ptr::copy_nonoverlapping(this.as_ptr().add(K), right.as_mut_ptr() as *mut T, R);
+            (left.assume_init(), right.assume_init())
+        }
+    }
+    fn push<const L: usize>(self, item: T) -> [T;L] {
+        self.concat([item])
+    }
+    fn pop<const L: usize>(self) -> ([T;L], T) {
+        self.split_last()
+    }
+    fn split_first<const L: usize>(self) -> (T, [T;L]) {
+        let ([first], rest) = Array::split_at::<1, L>(self);
+        (first, rest)
+    }
+    fn split_last<const L: usize>(self) -> ([T;L], T) {
+        let (rest, [last]) = Array::split_at::<L, 1>(self);
+        (rest, last)
+    }
+    fn insert<const I: usize, const L: usize>(self, item: T) -> [T;L] {
+        let () = Lengths::<N, 1, L>::SUM;
+        let () = Lengths::<I, 0, N>::FITS;
+        let this = ManuallyDrop::new(self);
+        let mut out = MaybeUninit::<[T;L]>::uninit();
+        unsafe {
+            let dst = out.as_mut_ptr() as *mut T;
+            core::ptr::copy_nonoverlapping(this.as_ptr(), dst, I);
+            dst.add(I).write(item);
+            core::ptr::copy_nonoverlapping(this.as_ptr().add(I), dst.add(I + 1), N - I);
+            out.assume_init()
+        }
+    }
+    fn remove<const I: usize, const L: usize>(self) -> ([T;L], T) {
+        let () = Lengths::<L, 1, N>::SUM;
+        let () = Lengths::<I, 1, N>::FITS;
+        let this = ManuallyDrop::new(self);
+        let mut out = MaybeUninit::<[T;L]>::uninit();
+        unsafe {
+            let dst = out.as_mut_ptr() as *mut T;
+            core::ptr::copy_nonoverlapping(this.as_ptr(), dst, I);
+            core::ptr::copy_nonoverlapping(this.as_ptr().add(I + 1), dst.add(I), L - I);
+            (out.assume_init(), this.as_ptr().add(I).read())
+        }
+    }
+    fn filter(self, mut pred: impl FnMut(&T) -> bool) -> ArrayVec<T, N> {
+        Array::into_iter(self).filter(|item| pred(item)).collect()
+    }
+    fn partition(self, mut pred: impl FnMut(&T) -> bool) -> (ArrayVec<T, N>, ArrayVec<T, N>) {
+        let mut yes = ArrayVec::new();
+        let mut no = ArrayVec::new();
+        for item in Array::into_iter(self) {
+            if pred(&item) {
+                yes.push(item);
+            } else {
+                no.push(item);
+            }
+        }
+        (yes, no)
+    }
+    fn filter_map<U>(self, f: impl FnMut(T) -> Option<U>) -> ArrayVec<U, N> {
+        Array::into_iter(self).filter_map(f).collect()
+    }
+    fn dedup_by_key<K: PartialEq>(self, mut key: impl FnMut(&T) -> K) -> ArrayVec<T, N> {
+        let mut out = ArrayVec::new();
+        let mut last = None;
+        for item in Array::into_iter(self) {
+            let k = key(&item);
+            if last.as_ref() != Some(&k) {
+                out.push(item);
+                last = Some(k);
+            }
+        }
+        out
+    }
+}
+
+
+/// A trait that exists for the sole purpose of allowing the unzip method to be put on arrays.
+///
+/// This method cannot be attached directly to the type `[(T,U);N]`.
+/// Trying to do so results in error [E0118].
+///
+/// [`ArrayUnzip<N>`] is implemented for, and only for, arrays of tuples with between two and eight elements,
+/// such as `[(T,U);N]` and `[(T,U,V);N]`.
+///
+/// [E0118]: https://doc.rust-lang.org/error-index.html#E0118
+/// [`ArrayUnzip<N>`]: ./trait.ArrayUnzip.html
+pub trait ArrayUnzip<const N: usize>: private::ArrayUnzipSealed where
+    // Self == [(T,U,...);N],
+{
+    /// The tuple of arrays, such as `([T;N],[U;N])`.
+    type Output;
+    /// Converts an array of tuples into a tuple of arrays.
+    ///
+    /// # Example
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use array_helpers::*;
+    /// let arr = [(1, 'a'), (2, 'b'), (3, 'c'), (4, 'd')];
+    /// assert_eq!(arr.unzip(), ([1,2,3,4], ['a','b','c','d']));
+    ///
+    /// let arr = [(1, 'a', "x"), (2, 'b', "y")];
+    /// assert_eq!(arr.unzip(), ([1,2], ['a','b'], ["x","y"]));
+    /// ```
+    fn unzip(self) -> Self::Output;
+}
+
+/// A trait that exists for the sole purpose of allowing the zip_all method to be put on tuples of arrays.
+///
+/// [`ArrayZip<N>`] is implemented for, and only for, tuples of between two and eight arrays of length `N`,
+/// such as `([T;N],[U;N])` and `([T;N],[U;N],[V;N])`.
+///
+/// [`ArrayZip<N>`]: ./trait.ArrayZip.html
+pub trait ArrayZip<const N: usize>: private::ArrayZipSealed where
+    // Self == ([T;N],[U;N],...),
+{
+    /// The array of tuples, such as `[(T,U);N]`.
+    type Output;
+    /// Converts a tuple of arrays into an array of tuples.
+    ///
+    /// # Example
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use array_helpers::*;
+    /// let arrs = ([1, 2], ['a', 'b'], ["x", "y"], [true, false]);
+    /// assert_eq!(arrs.zip_all(), [(1, 'a', "x", true), (2, 'b', "y", false)]);
+    /// ```
+    fn zip_all(self) -> Self::Output;
+}
+
+/// Implements `ArrayZip` and `ArrayUnzip` for one tuple arity.
+///
+/// Each element type is listed with the tuple index it lives at, and a name for the array holding it.
+macro_rules! impl_tuple_arrays {
+    ($($T:ident $i:tt $arr:ident),+) => {
+        impl<$($T,)+ const N: usize> ArrayUnzip<N> for [($($T,)+); N] {
+            type Output = ($([$T;N],)+);
+            fn unzip(self) -> Self::Output {
+                $(let mut $arr = ArrayBuilder::new();)+
+                for item in Array::into_iter(self) {
+                    $($arr.push(item.$i);)+
+                }
+                ($($arr.finish(),)+)
+            }
+        }
+        impl<$($T,)+ const N: usize> private::ArrayUnzipSealed for [($($T,)+); N] {}
+
+        impl<$($T,)+ const N: usize> ArrayZip<N> for ($([$T;N],)+) {
+            type Output = [($($T,)+); N];
+            fn zip_all(self) -> Self::Output {
+                // If this is dropped partway, each iterator drops exactly the elements it has not yet yielded.
+                $(let mut $arr = Array::into_iter(self.$i);)+
+                new(|_| ($($arr.next().unwrap(),)+))
+            }
+        }
+        impl<$($T,)+ const N: usize> private::ArrayZipSealed for ($([$T;N],)+) {}
+    };
+}
+
+impl_tuple_arrays!(A 0 a, B 1 b);
+impl_tuple_arrays!(A 0 a, B 1 b, C 2 c);
+impl_tuple_arrays!(A 0 a, B 1 b, C 2 c, D 3 d);
+impl_tuple_arrays!(A 0 a, B 1 b, C 2 c, D 3 d, E 4 e);
+impl_tuple_arrays!(A 0 a, B 1 b, C 2 c, D 3 d, E 4 e, F 5 f);
+impl_tuple_arrays!(A 0 a, B 1 b, C 2 c, D 3 d, E 4 e, F 5 f, G 6 g);
+impl_tuple_arrays!(A 0 a, B 1 b, C 2 c, D 3 d, E 4 e, F 5 f, G 6 g, H 7 h);
+
+/// A trait that exists for the sole purpose of allowing the transpose method to be put on arrays.
+///
+/// This method cannot be attached directly to the type `[[T;M];N]`.
+/// Trying to do so results in error [E0118].
+///
+/// [`ArrayUnzip<T, M, N>`] is implemented for, and only for, the type `[[T;M];N]`.
+///
+/// [E0118]: https://doc.rust-lang.org/error-index.html#E0118
+/// [`ArrayUnzip<T, M, N>`]: ./trait.ArrayUnzip.html
+pub trait ArrayTranspose<T, const M: usize, const N: usize>: private::ArrayTransposeSealed where
+    // Self == [[T;M];N],
+{
+    /// Transposes a 2D array.
+    ///
+    /// # Example
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use array_helpers::*;
+    /// let arr = [[1,2,3],[4,5,6]];
+    /// assert_eq!(arr.transpose(), [[1, 4], [2, 5], [3, 6]]);
+    /// ```
+    fn transpose(self) -> [[T;N];M];
+}
+
+impl<T, const M: usize, const N: usize> ArrayTranspose<T, M, N> for [[T;M];N] {
+    fn transpose(self) -> [[T;N];M] {
+        // Each row is consumed front-to-back, so a row iterator keeps exactly the elements not yet moved.
+        let mut rows = self.map(Array::into_iter);
+        new(|_| new(|j| rows[j].next().unwrap()))
+    }
+}
+
+/// A trait that exists for the sole purpose of allowing the transpose_option method to be put on arrays.
+///
+/// This method cannot be attached directly to the type `[Option<T>;N]`.
+/// Trying to do so results in error [E0118].
+///
+/// [`ArrayTransposeOption<T, N>`] is implemented for, and only for, the type `[Option<T>;N]`.
+///
+/// [E0118]: https://doc.rust-lang.org/error-index.html#E0118
+/// [`ArrayTransposeOption<T, N>`]: ./trait.ArrayTransposeOption.html
+pub trait ArrayTransposeOption<T, const N: usize>: private::ArrayTransposeOptionSealed where
+    // Self == [Option<T>;N],
+{
+    /// Converts an array of options into an option of an array, which is `Some` only if every element is `Some`.
+    ///
+    /// If some element is `None`, the elements in the other options are dropped.
+    ///
+    /// # Example
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use array_helpers::*;
+    /// assert_eq!([Some(1), Some(2), Some(3)].transpose_option(), Some([1, 2, 3]));
+    /// assert_eq!([Some(1), None, Some(3)].transpose_option(), None);
+    /// ```
+    fn transpose_option(self) -> Option<[T;N]>;
+}
+
+impl<T, const N: usize> ArrayTransposeOption<T, N> for [Option<T>;N] {
+    fn transpose_option(self) -> Option<[T;N]> {
+        Array::try_map(self, |item| item.ok_or(())).ok()
+    }
+}
+
+/// A trait that exists for the sole purpose of allowing the transpose_result method to be put on arrays.
+///
+/// This method cannot be attached directly to the type `[Result<T,E>;N]`.
+/// Trying to do so results in error [E0118].
+///
+/// [`ArrayTransposeResult<T, E, N>`] is implemented for, and only for, the type `[Result<T,E>;N]`.
+///
+/// [E0118]: https://doc.rust-lang.org/error-index.html#E0118
+/// [`ArrayTransposeResult<T, E, N>`]: ./trait.ArrayTransposeResult.html
+pub trait ArrayTransposeResult<T, E, const N: usize>: private::ArrayTransposeResultSealed where
+    // Self == [Result<T,E>;N],
+{
+    /// Converts an array of results into a result of an array, which is the first error if there is one.
+    ///
+    /// If some element is an error, the values in the other results, and any later errors, are dropped.
+    ///
+    /// # Example
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use array_helpers::*;
+    /// let arr: [Result<u8, &str>; 3] = [Ok(1), Ok(2), Ok(3)];
+    /// assert_eq!(arr.transpose_result(), Ok([1, 2, 3]));
+    ///
+    /// let arr: [Result<u8, &str>; 3] = [Ok(1), Err("two"), Err("three")];
+    /// assert_eq!(arr.transpose_result(), Err("two"));
+    /// ```
+    fn transpose_result(self) -> Result<[T;N], E>;
+}
+
+impl<T, E, const N: usize> ArrayTransposeResult<T, E, N> for [Result<T,E>;N] {
+    fn transpose_result(self) -> Result<[T;N], E> {
+        Array::try_map(self, |item| item)
+    }
+}
+
+/// A trait that exists for the sole purpose of allowing the transpose_array method to be put on optional arrays.
+///
+/// [`OptionArrayTranspose<T, N>`] is implemented for, and only for, the type `Option<[T;N]>`.
+///
+/// [`OptionArrayTranspose<T, N>`]: ./trait.OptionArrayTranspose.html
+pub trait OptionArrayTranspose<T, const N: usize>: private::OptionArrayTransposeSealed where
+    // Self == Option<[T;N]>,
+{
+    /// Converts an option of an array into an array of options, which are all `Some` or all `None`.
+    ///
+    /// This is the reverse of [`transpose_option`].
+    ///
+    /// # Example
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use array_helpers::*;
+    /// assert_eq!(Some([1, 2]).transpose_array(), [Some(1), Some(2)]);
+    /// assert_eq!(None::<[u8; 2]>.transpose_array(), [None, None]);
+    /// ```
+    ///
+    /// [`transpose_option`]: ./trait.ArrayTransposeOption.html#tymethod.transpose_option
+    fn transpose_array(self) -> [Option<T>;N];
+}
+
+impl<T, const N: usize> OptionArrayTranspose<T, N> for Option<[T;N]> {
+    fn transpose_array(self) -> [Option<T>;N] {
+        match self {
+            Some(arr) => Array::map(arr, Some),
+            None => new(|_| None),
+        }
+    }
+}
+
+/// A trait that exists for the sole purpose of allowing the flatten methods to be put on arrays.
+///
+/// These methods cannot be attached directly to the type `[[T;M];N]`.
+/// Trying to do so results in error [E0118].
+///
+/// [`ArrayFlatten<T, M, N>`] is implemented for, and only for, the type `[[T;M];N]`.
+///
+/// The length of the flattened array must be written as a separate parameter `L`,
+/// because stable Rust cannot yet write `[T; M * N]`.
+/// It is usually inferred. If it is not equal to `M * N`, the program fails to compile.
+///
+/// [E0118]: https://doc.rust-lang.org/error-index.html#E0118
+/// [`ArrayFlatten<T, M, N>`]: ./trait.ArrayFlatten.html
+pub trait ArrayFlatten<T, const M: usize, const N: usize>: private::ArrayFlattenSealed where
+    // Self == [[T;M];N],
+{
+    /// Concatenates the rows of a 2D array.
+    ///
+    /// This does not move any elements; it only reinterprets the array.
+    ///
+    /// # Example
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use array_helpers::*;
+    /// let arr = [[1,2,3],[4,5,6]];
+    /// let flat: [usize; 6] = arr.flatten();
+    /// assert_eq!(flat, [1, 2, 3, 4, 5, 6]);
+    /// ```
+    ///
+    /// The length must be correct:
+    ///
+    /// ```compile_fail
+    /// # use array_helpers::*;
+    /// let arr = [[1,2,3],[4,5,6]];
+    /// let flat: [usize; 5] = arr.flatten();
+    /// ```
+    fn flatten<const L: usize>(self) -> [T;L];
+    /// Views a 2D array as a flat array.
+    ///
+    /// # Example
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use array_helpers::*;
+    /// let arr = [[1,2,3],[4,5,6]];
+    /// assert_eq!(arr.flatten_ref::<6>(), &[1, 2, 3, 4, 5, 6]);
+    /// ```
+    fn flatten_ref<const L: usize>(&self) -> &[T;L];
+    /// Mutably views a 2D array as a flat array.
+    ///
+    /// # Example
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use array_helpers::*;
+    /// let mut arr = [[1,2,3],[4,5,6]];
+    /// arr.flatten_mut::<6>()[3] = 40;
+    /// assert_eq!(arr, [[1, 2, 3], [40, 5, 6]]);
+    /// ```
+    fn flatten_mut<const L: usize>(&mut self) -> &mut [T;L];
+}
+
+impl<T, const M: usize, const N: usize> ArrayFlatten<T, M, N> for [[T;M];N] {
+    fn flatten<const L: usize>(self) -> [T;L] {
+        let () = Lengths::<M, N, L>::PRODUCT;
+        unsafe { unsafe_transmute(self) }
+    }
+    fn flatten_ref<const L: usize>(&self) -> &[T;L] {
+        let () = Lengths::<M, N, L>::PRODUCT;
+        unsafe { &*(self as *const [[T;M];N] as *const [T;L]) }
+    }
+    fn flatten_mut<const L: usize>(&mut self) -> &mut [T;L] {
+        let () = Lengths::<M, N, L>::PRODUCT;
+        unsafe { &mut *(self as *mut [[T;M];N] as *mut [T;L]) }
+    }
+}
+
+#[cfg(feature = "alloc")]
+/// A trait that exists for the sole purpose of allowing methods to be put on boxed arrays.
+///
+/// These methods work directly on the heap, so they can handle arrays too large for the stack.
+///
+/// [`BoxedArray<T, N>`] is implemented for, and only for, the type `Box<[T;N]>`.
+///
+/// [`BoxedArray<T, N>`]: ./trait.BoxedArray.html
+pub trait BoxedArray<T, const N: usize>: private::BoxedArraySealed where
+    // Self == Box<[T;N]>,
+{
+    /// Creates an iterator from a boxed array.
+    ///
+    /// # Example
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use array_helpers::*;
+    /// let arr: Box<[usize; 3]> = Box::new([1, 2, 3]);
+    /// let mut iter = arr.into_iter();
+    /// assert_eq!(Some(1), iter.next());
+    /// assert_eq!(Some(3), iter.next_back());
+    /// assert_eq!(Some(2), iter.next());
+    /// assert_eq!(None, iter.next());
+    /// ```
+    fn into_iter(self) -> BoxedIntoIter<T, N>;
+    /// Takes a closure and creates a new boxed array by calling that closure on each element.
+    ///
+    /// If `T` and `U` have the same size and alignment, the allocation is reused.
+    ///
+    /// # Example
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use array_helpers::*;
+    /// // Will not stack overflow
+    /// let arr: Box<[u64; 10_000_000]> = new_boxed(|n| n as u64);
+    /// let arr: Box<[i64; 10_000_000]> = arr.map(|x| -(x as i64));
+    /// assert_eq!(arr[9_999_999], -9_999_999);
+    /// ```
+    fn map<U>(self, f: impl FnMut(T) -> U) -> Box<[U;N]>;
+    /// Converts a pair of boxed arrays into a boxed array of pairs.
+    ///
+    /// # Example
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use array_helpers::*;
+    /// let arr1 = Box::new([1, 2, 3]);
+    /// let arr2 = Box::new(['a', 'b', 'c']);
+    /// assert_eq!(arr1.zip(arr2), Box::new([(1, 'a'), (2, 'b'), (3, 'c')]));
+    /// ```
+    fn zip<U>(self, other: Box<[U;N]>) -> Box<[(T,U);N]>;
+}
+
+#[cfg(feature = "alloc")]
+impl<T, const N: usize> BoxedArray<T, N> for Box<[T;N]> {
+    fn into_iter(self) -> BoxedIntoIter<T, N> {
+        boxed::into_iter(self)
+    }
+    fn map<U>(self, f: impl FnMut(T) -> U) -> Box<[U;N]> {
+        if core::alloc::Layout::new::<T>() == core::alloc::Layout::new::<U>() {
+            boxed::map_in_place(self, f)
+        } else {
+            boxed::from_vec(BoxedArray::into_iter(self).map(f).collect())
+        }
+    }
+    fn zip<U>(self, other: Box<[U;N]>) -> Box<[(T,U);N]> {
+        boxed::from_vec(BoxedArray::into_iter(self).zip(BoxedArray::into_iter(other)).collect())
+    }
+}
+
+#[cfg(feature = "alloc")]
+/// A trait that exists for the sole purpose of allowing the unzip method to be put on boxed arrays.
+///
+/// [`BoxedArrayUnzip<T, U, N>`] is implemented for, and only for, the type `Box<[(T,U);N]>`.
+///
+/// [`BoxedArrayUnzip<T, U, N>`]: ./trait.BoxedArrayUnzip.html
+pub trait BoxedArrayUnzip<T, U, const N: usize>: private::BoxedArrayUnzipSealed where
+    // Self == Box<[(T,U);N]>,
+{
+    /// Converts a boxed array of pairs into a pair of boxed arrays.
+    ///
+    /// # Example
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use array_helpers::*;
+    /// let arr = Box::new([(1, 'a'), (2, 'b'), (3, 'c')]);
+    /// assert_eq!(arr.unzip(), (Box::new([1, 2, 3]), Box::new(['a', 'b', 'c'])));
+    /// ```
+    fn unzip(self) -> (Box<[T;N]>, Box<[U;N]>);
+}
+
+#[cfg(feature = "alloc")]
+impl<T, U, const N: usize> BoxedArrayUnzip<T, U, N> for Box<[(T,U);N]> {
+    fn unzip(self) -> (Box<[T;N]>, Box<[U;N]>) {
+        let (out1, out2): (Vec<T>, Vec<U>) = BoxedArray::into_iter(self).unzip();
+        (boxed::from_vec(out1), boxed::from_vec(out2))
+    }
+}
+
+#[cfg(feature = "alloc")]
+/// A trait that exists for the sole purpose of allowing the transpose method to be put on boxed arrays.
+///
+/// [`BoxedArrayTranspose<T, M, N>`] is implemented for, and only for, the type `Box<[[T;M];N]>`.
+///
+/// [`BoxedArrayTranspose<T, M, N>`]: ./trait.BoxedArrayTranspose.html
+pub trait BoxedArrayTranspose<T, const M: usize, const N: usize>: private::BoxedArrayTransposeSealed where
+    // Self == Box<[[T;M];N]>,
+{
+    /// Transposes a boxed 2D array, reusing its allocation.
+    ///
+    /// # Example
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use array_helpers::*;
+    /// let arr = Box::new([[1,2,3],[4,5,6]]);
+    /// assert_eq!(arr.transpose(), Box::new([[1, 4], [2, 5], [3, 6]]));
+    ///
+    /// // Zero-sized elements are fine, even if there are more than `usize::MAX` of them.
+    /// let arr: Box<[[(); usize::MAX]; 2]> = Box::new([[(); usize::MAX]; 2]);
+    /// let arr: Box<[[(); 2]; usize::MAX]> = arr.transpose();
+    /// ```
+    fn transpose(self) -> Box<[[T;N];M]>;
+}
+
+#[cfg(feature = "alloc")]
+impl<T, const M: usize, const N: usize> BoxedArrayTranspose<T, M, N> for Box<[[T;M];N]> {
+    fn transpose(self) -> Box<[[T;N];M]> {
+        boxed::transpose_in_place(self)
+    }
+}
+
+/// A trait that exists for the sole purpose of allowing iterators to be collected into arrays.
+///
+/// [`IteratorExt`] is implemented for every iterator.
+///
+/// [`IteratorExt`]: ./trait.IteratorExt.html
+pub trait IteratorExt: Iterator {
+    /// Collects exactly `N` items into an array.
+    ///
+    /// This is the same as [`from_iter`].
+    ///
+    /// # Example
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use array_helpers::*;
+    /// let arr = "a,b,c".split(',').collect_array::<3>();
+    /// assert_eq!(arr.unwrap(), ["a", "b", "c"]);
+    ///
+    /// assert!("a,b".split(',').collect_array::<3>().is_err());
+    /// ```
+    ///
+    /// [`from_iter`]: ./fn.from_iter.html
+    fn collect_array<const N: usize>(self) -> Result<[Self::Item;N], FromIterError<Self::Item, Self, N>> where Self: Sized {
+        from_iter::collect(self)
+    }
+}
+
+impl<I: Iterator> IteratorExt for I {}
+
+/// A private module, whose traits cannot be implemented by users of the crate.
 /// The traits in the main module require these as supertraits, so they also cannot be implemented by users of this crate.
 /// This ensures that the methods in this module only apply to arrays.
 mod private {
+    #[cfg(feature = "alloc")]
+    use alloc::boxed::Box;
+
     pub trait ArraySealed {}
     impl<T, const N: usize> ArraySealed for [T;N] {}
 
+    // These are implemented by `impl_tuple_arrays!`, once per tuple arity.
     pub trait ArrayUnzipSealed {}
-    impl<T, U, const N: usize> ArrayUnzipSealed for [(T,U);N] {}
+    pub trait ArrayZipSealed {}
 
     pub trait ArrayTransposeSealed {}
     impl<T, const M: usize, const N: usize> ArrayTransposeSealed for [[T;M];N] {}
+
+    pub trait ArrayTransposeOptionSealed {}
+    impl<T, const N: usize> ArrayTransposeOptionSealed for [Option<T>;N] {}
+
+    pub trait ArrayTransposeResultSealed {}
+    impl<T, E, const N: usize> ArrayTransposeResultSealed for [Result<T,E>;N] {}
+
+    pub trait OptionArrayTransposeSealed {}
+    impl<T, const N: usize> OptionArrayTransposeSealed for Option<[T;N]> {}
+
+    pub trait ArrayFlattenSealed {}
+    impl<T, const M: usize, const N: usize> ArrayFlattenSealed for [[T;M];N] {}
+
+    #[cfg(feature = "alloc")]
+    pub trait BoxedArraySealed {}
+    #[cfg(feature = "alloc")]
+    impl<T, const N: usize> BoxedArraySealed for Box<[T;N]> {}
+
+    #[cfg(feature = "alloc")]
+    pub trait BoxedArrayUnzipSealed {}
+    #[cfg(feature = "alloc")]
+    impl<T, U, const N: usize> BoxedArrayUnzipSealed for Box<[(T,U);N]> {}
+
+    #[cfg(feature = "alloc")]
+    pub trait BoxedArrayTransposeSealed {}
+    #[cfg(feature = "alloc")]
+    impl<T, const M: usize, const N: usize> BoxedArrayTransposeSealed for Box<[[T;M];N]> {}
 }
@@ -0,0 +1,154 @@
+//! Low-level helpers for arrays of `MaybeUninit`.
+//!
+//! These are the building blocks the rest of the crate uses to initialize arrays one element at a time.
+//! Most of them are `unsafe`; each documents exactly what the caller must guarantee.
+
+use core::mem::MaybeUninit;
+use crate::utils::unsafe_transmute;
+
+
+/// Creates an array of uninitialized elements.
+///
+/// This is safe, because a `MaybeUninit` is allowed to be uninitialized.
+///
+/// # Example
+///
+/// Basic usage:
+///
+/// ```
+/// # use array_helpers::raw::*;
+/// use std::mem::MaybeUninit;
+/// let mut arr = uninit_array::<String, 2>();
+/// arr[0] = MaybeUninit::new("a".to_string());
+/// arr[1] = MaybeUninit::new("b".to_string());
+/// assert_eq!(unsafe { assume_init_array(arr) }, ["a", "b"]);
+/// ```
+pub fn uninit_array<T, const N: usize>() -> [MaybeUninit<T>; N] {
+	push_maybe_uninit(MaybeUninit::uninit())
+}
+
+/// Converts an array of initialized `MaybeUninit`s into an array of values.
+///
+/// # Safety
+///
+/// Every element of `arr` must be initialized.
+///
+/// # Example
+///
+/// Basic usage:
+///
+/// ```
+/// # use array_helpers::raw::*;
+/// use std::mem::MaybeUninit;
+/// let arr = [MaybeUninit::new(1), MaybeUninit::new(2)];
+/// assert_eq!(unsafe { assume_init_array(arr) }, [1, 2]);
+/// ```
+pub unsafe fn assume_init_array<T, const N: usize>(arr: [MaybeUninit<T>; N]) -> [T;N] {
+	pull_maybe_uninit(arr).assume_init()
+}
+
+/// Reinterprets a possibly-initialized array of things as an array of possibly-initialized things.
+///
+/// # Example
+///
+/// Basic usage:
+///
+/// ```
+/// # use array_helpers::raw::*;
+/// use std::mem::MaybeUninit;
+/// let arr: [MaybeUninit<u8>; 3] = push_maybe_uninit(MaybeUninit::new([1, 2, 3]));
+/// assert_eq!(unsafe { arr[2].assume_init() }, 3);
+/// ```
+pub fn push_maybe_uninit<T, const N: usize>(arr: MaybeUninit<[T;N]>) -> [MaybeUninit<T>;N] {
+	unsafe { unsafe_transmute(arr) }
+}
+
+/// Reinterprets an array of possibly-initialized things as a possibly-initialized array of things.
+///
+/// # Example
+///
+/// Basic usage:
+///
+/// ```
+/// # use array_helpers::raw::*;
+/// use std::mem::MaybeUninit;
+/// let arr = pull_maybe_uninit([MaybeUninit::new(1u8), MaybeUninit::new(2)]);
+/// assert_eq!(unsafe { arr.assume_init() }, [1, 2]);
+/// ```
+pub fn pull_maybe_uninit<T, const N: usize>(arr: [MaybeUninit<T>;N]) -> MaybeUninit<[T;N]> {
+	unsafe { unsafe_transmute(arr) }
+}
+
+/// Moves the element at index `i` out of an array, leaving that slot uninitialized.
+///
+/// Panics if `i` is out of bounds.
+///
+/// # Safety
+///
+/// `arr[i]` must be initialized.
+/// Afterwards it is uninitialized, so it must not be read or dropped again until it is overwritten.
+///
+/// # Example
+///
+/// Basic usage:
+///
+/// ```
+/// # use array_helpers::raw::*;
+/// use std::mem::MaybeUninit;
+/// let mut arr = [MaybeUninit::new("a".to_string()), MaybeUninit::new("b".to_string())];
+/// let b = unsafe { extract(&mut arr, 1) };
+/// assert_eq!(b, "b");
+/// unsafe { arr[0].assume_init_drop() };
+/// ```
+pub unsafe fn extract<T, const N: usize>(arr: &mut [MaybeUninit<T>;N], i: usize) -> T {
+	core::mem::replace(&mut arr[i], MaybeUninit::uninit()).assume_init()
+}
+
+/// Views a slice of initialized `MaybeUninit`s as a slice of values.
+///
+/// This is typically used on the initialized prefix of a partially-initialized array, as in `&arr[..len]`.
+///
+/// # Safety
+///
+/// Every element of `slice` must be initialized.
+///
+/// # Example
+///
+/// Basic usage:
+///
+/// ```
+/// # use array_helpers::raw::*;
+/// use std::mem::MaybeUninit;
+/// let mut arr = uninit_array::<u32, 4>();
+/// arr[0] = MaybeUninit::new(7);
+/// arr[1] = MaybeUninit::new(8);
+/// assert_eq!(unsafe { assume_init_ref(&arr[..2]) }, [7, 8]);
+/// ```
+pub unsafe fn assume_init_ref<T>(slice: &[MaybeUninit<T>]) -> &[T] {
+	core::slice::from_raw_parts(slice.as_ptr() as *const T, slice.len())
+}
+
+/// Views a slice of initialized `MaybeUninit`s as a mutable slice of values.
+///
+/// This is typically used on the initialized prefix of a partially-initialized array, as in `&mut arr[..len]`.
+///
+/// # Safety
+///
+/// Every element of `slice` must be initialized.
+///
+/// # Example
+///
+/// Basic usage:
+///
+/// ```
+/// # use array_helpers::raw::*;
+/// use std::mem::MaybeUninit;
+/// let mut arr = uninit_array::<u32, 4>();
+/// arr[0] = MaybeUninit::new(7);
+/// let prefix = unsafe { assume_init_mut(&mut arr[..1]) };
+/// prefix[0] += 1;
+/// assert_eq!(unsafe { arr[0].assume_init() }, 8);
+/// ```
+pub unsafe fn assume_init_mut<T>(slice: &mut [MaybeUninit<T>]) -> &mut [T] {
+	core::slice::from_raw_parts_mut(slice.as_mut_ptr() as *mut T, slice.len())
+}
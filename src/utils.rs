@@ -1,24 +1,107 @@
 use core::mem::MaybeUninit;
+pub use crate::raw::{uninit_array, assume_init_array, push_maybe_uninit, extract, assume_init_ref, assume_init_mut};
 
 /// This is the same thing as core::mem::transmute, but without the check that the types are the same size.
 /// This is necessary because core::mem::transmute refuses to transmute between dependently-sized types. 
-unsafe fn unsafe_transmute<A, B>(a: A) -> B {
+pub unsafe fn unsafe_transmute<A, B>(a: A) -> B {
 	let ptr = &a as *const A as *const B;
 	core::mem::forget(a);
 	core::ptr::read(ptr)
 }
 
-/// Reinterpret a possibly-initialized array of things as an array of possibly-initialized things.
-pub fn push_maybe_uninit<T, const N: usize>(arr: MaybeUninit<[T;N]>) -> [MaybeUninit<T>;N] {
-	unsafe { unsafe_transmute(arr) }
+/// Compile-time checks on array lengths.
+///
+/// Evaluating one of these constants (as in `let () = Lengths::<A, B, C>::PRODUCT;`) fails to compile if the check does not hold.
+/// The check happens when the surrounding function is instantiated, so it applies to the caller's actual lengths.
+pub struct Lengths<const A: usize, const B: usize, const C: usize>;
+
+impl<const A: usize, const B: usize, const C: usize> Lengths<A,B,C> {
+	/// Checks that `A * B == C`.
+	pub const PRODUCT: () = assert!(A * B == C, "array length is not the product of the other two lengths");
+	/// Checks that `A + B == C`.
+	pub const SUM: () = assert!(A + B == C, "array length is not the sum of the other two lengths");
+	/// Checks that `A + B <= C`.
+	pub const FITS: () = assert!(A + B <= C, "index out of bounds for array length");
+}
+
+/// An array under construction, whose first `len` elements are initialized.
+///
+/// If this is dropped before being finished (for example, because a closure panicked),
+/// the initialized elements are dropped, and the uninitialized ones are left alone.
+pub struct ArrayBuilder<T, const N: usize> {
+	/// arr[0..len] is initialized. arr[len..N] is uninitialized.
+	arr: [MaybeUninit<T>; N],
+	len: usize,
+}
+
+impl<T, const N: usize> ArrayBuilder<T,N> {
+	/// Create a builder with no initialized elements.
+	pub fn new() -> Self {
+		Self {
+			arr: uninit_array(),
+			len: 0,
+		}
+	}
+
+	/// Initialize the next element. Panics if the array is already full.
+	pub fn push(&mut self, item: T) {
+		self.arr[self.len] = MaybeUninit::new(item);
+		self.len += 1;
+	}
+
+	/// The number of initialized elements.
+	pub fn len(&self) -> usize {
+		self.len
+	}
+
+	/// The initialized elements.
+	pub fn as_slice(&self) -> &[T] {
+		unsafe { core::slice::from_raw_parts(self.arr.as_ptr() as *const T, self.len) }
+	}
+
+	/// The initialized elements.
+	pub fn as_mut_slice(&mut self) -> &mut [T] {
+		unsafe { core::slice::from_raw_parts_mut(self.arr.as_mut_ptr() as *mut T, self.len) }
+	}
+
+	/// Take the finished array. Panics if it is not full.
+	pub fn finish(self) -> [T;N] {
+		assert!(self.len == N, "ArrayBuilder::finish called on an incomplete array");
+		let this = core::mem::ManuallyDrop::new(self);
+		unsafe { assume_init_array(core::ptr::read(&this.arr)) }
+	}
+}
+
+impl<T, const N: usize> Drop for ArrayBuilder<T,N> {
+	fn drop(&mut self) {
+		unsafe { core::ptr::drop_in_place(self.as_mut_slice()) }
+	}
+}
+
+/// Guards the initialized prefix of an array that is being built in place.
+///
+/// If this is dropped (for example, because a closure panicked), the first `len` elements are dropped.
+struct SlotGuard<T> {
+	ptr: *mut T,
+	len: usize,
 }
 
-/// Reinterpret an array of possibly-initialized things as a possibly-initialized array of things.
-pub fn pull_maybe_uninit<T, const N: usize>(arr: [MaybeUninit<T>;N]) -> MaybeUninit<[T;N]> {
-	unsafe { unsafe_transmute(arr) }
+impl<T> Drop for SlotGuard<T> {
+	fn drop(&mut self) {
+		unsafe { core::ptr::drop_in_place(core::ptr::slice_from_raw_parts_mut(self.ptr, self.len)) }
+	}
 }
 
-/// Extract a value from an array, leaving it uninitialized. Undefined Behavior if it is already uninitialized.
-pub unsafe fn extract<T, const N: usize>(arr: &mut [MaybeUninit<T>;N], i: usize) -> T {
-	core::mem::replace(&mut arr[i], MaybeUninit::uninit()).assume_init()
+/// Initialize an array in place, by calling a fallible closure on each index.
+///
+/// If the closure fails or panics, the elements already created are dropped, and the slot is left uninitialized.
+pub fn try_init_slot<T, E, const N: usize>(slot: &mut MaybeUninit<[T;N]>, mut f: impl FnMut(usize) -> Result<T, E>) -> Result<&mut [T;N], E> {
+	let mut guard = SlotGuard { ptr: slot.as_mut_ptr() as *mut T, len: 0 };
+	while guard.len < N {
+		let item = f(guard.len)?;
+		unsafe { guard.ptr.add(guard.len).write(item) };
+		guard.len += 1;
+	}
+	core::mem::forget(guard);
+	Ok(unsafe { &mut *slot.as_mut_ptr() })
 }
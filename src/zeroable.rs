@@ -0,0 +1,25 @@
+/// Types for which a value whose bytes are all zero is valid.
+///
+/// This allows arrays of these types to be created from zeroed memory,
+/// as in [`new_boxed_zeroed`].
+///
+/// # Safety
+///
+/// A value of this type must be valid when every byte of it is zero.
+/// For example, this holds for integers, floats and raw pointers, but not for references or `NonZeroU32`.
+///
+/// [`new_boxed_zeroed`]: ./fn.new_boxed_zeroed.html
+pub unsafe trait Zeroable {}
+
+macro_rules! impl_zeroable {
+	($($t:ty),*) => {
+		$(unsafe impl Zeroable for $t {})*
+	};
+}
+
+impl_zeroable!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64, bool, char, ());
+
+unsafe impl<T> Zeroable for *const T {}
+unsafe impl<T> Zeroable for *mut T {}
+unsafe impl<T: Zeroable> Zeroable for core::num::Wrapping<T> {}
+unsafe impl<T: Zeroable, const N: usize> Zeroable for [T;N] {}
@@ -0,0 +1,104 @@
+//! Checks that `new_in` and `map_in` allocate from, and free to, the right allocators.
+#![cfg(feature = "allocator_api")]
+#![feature(allocator_api)]
+
+use array_helpers::*;
+use std::alloc::{AllocError, Allocator, Global, Layout};
+use std::cell::Cell;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::ptr::NonNull;
+
+/// Forwards to the global allocator, counting the allocations that are still live.
+struct Counting<'a>(&'a Cell<isize>);
+
+unsafe impl Allocator for Counting<'_> {
+    fn allocate(&self, layout: Layout) -> Result<NonNull<[u8]>, AllocError> {
+        let ptr = Global.allocate(layout)?;
+        self.0.set(self.0.get() + 1);
+        Ok(ptr)
+    }
+    unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout) {
+        self.0.set(self.0.get() - 1);
+        Global.deallocate(ptr, layout)
+    }
+}
+
+/// Increments a counter when created, and decrements it when dropped.
+struct Token<'a>(&'a Cell<isize>);
+
+impl<'a> Token<'a> {
+    fn new(live: &'a Cell<isize>) -> Self {
+        live.set(live.get() + 1);
+        Token(live)
+    }
+}
+
+impl Drop for Token<'_> {
+    fn drop(&mut self) {
+        self.0.set(self.0.get() - 1);
+    }
+}
+
+fn panics<R>(f: impl FnOnce() -> R) -> bool {
+    catch_unwind(AssertUnwindSafe(f)).is_err()
+}
+
+#[test]
+fn new_in_allocates_once() {
+    let allocs = Cell::new(0);
+    let arr: Box<[usize; 1000], _> = new_in(Counting(&allocs), |i| i);
+    assert_eq!(allocs.get(), 1);
+    assert_eq!(arr[999], 999);
+    drop(arr);
+    assert_eq!(allocs.get(), 0);
+}
+
+#[test]
+fn new_in_frees_on_panic() {
+    let allocs = Cell::new(0);
+    let live = Cell::new(0);
+    assert!(panics(|| {
+        new_in::<Token, _, 5>(Counting(&allocs), |i| {
+            if i == 3 {
+                panic!();
+            }
+            Token::new(&live)
+        })
+    }));
+    assert_eq!(live.get(), 0);
+    assert_eq!(allocs.get(), 0);
+}
+
+#[test]
+fn map_in_moves_between_allocators() {
+    let from = Cell::new(0);
+    let to = Cell::new(0);
+    let arr: Box<[u8; 4], _> = new_in(Counting(&from), |i| i as u8);
+    let arr: Box<[u64; 4], _> = map_in(arr, Counting(&to), |x| x as u64 * 10);
+    assert_eq!(*arr, [0, 10, 20, 30]);
+    assert_eq!(from.get(), 0);
+    assert_eq!(to.get(), 1);
+    drop(arr);
+    assert_eq!(to.get(), 0);
+}
+
+#[test]
+fn map_in_frees_on_panic() {
+    let from = Cell::new(0);
+    let to = Cell::new(0);
+    let live = Cell::new(0);
+    let arr: Box<[Token; 5], _> = new_in(Counting(&from), |_| Token::new(&live));
+    let mut calls = 0;
+    assert!(panics(|| {
+        map_in(arr, Counting(&to), |t| {
+            calls += 1;
+            if calls == 3 {
+                panic!();
+            }
+            (t, Token::new(&live))
+        })
+    }));
+    assert_eq!(live.get(), 0);
+    assert_eq!(from.get(), 0);
+    assert_eq!(to.get(), 0);
+}
@@ -0,0 +1,70 @@
+//! Exercises `ArrayDeque` on types that own heap memory.
+//!
+//! Drop counting under panics lives in `panic_safety.rs`; these check the values.
+
+use array_helpers::*;
+
+/// A full deque holding `"0"..="3"`, whose front element sits at the end of the buffer.
+fn wrapped() -> ArrayDeque<String, 4> {
+    let mut q = ArrayDeque::new();
+    for _ in 0..3 {
+        q.push_back(String::new());
+        q.pop_front();
+    }
+    for i in 0..4 {
+        q.push_back(i.to_string());
+    }
+    q
+}
+
+#[test]
+fn push_and_pop_at_both_ends() {
+    let mut q = ArrayDeque::<String, 3>::new();
+    q.push_back("b".to_string());
+    q.push_front("a".to_string());
+    q.push_back("c".to_string());
+    assert!(q.is_full());
+    assert_eq!(q.try_push_front("z".to_string()).unwrap_err().into_inner(), "z");
+    assert_eq!(q.front().map(String::as_str), Some("a"));
+    assert_eq!(q.back().map(String::as_str), Some("c"));
+    assert_eq!(q.get(1).map(String::as_str), Some("b"));
+    assert_eq!(q.pop_front().as_deref(), Some("a"));
+    assert_eq!(q.pop_back().as_deref(), Some("c"));
+    assert_eq!(q.pop_back().as_deref(), Some("b"));
+    assert_eq!(q.pop_front(), None);
+    assert!(q.is_empty());
+}
+
+#[test]
+fn as_slices_splits_at_the_wraparound() {
+    let q = wrapped();
+    let (front, back) = q.as_slices();
+    assert_eq!(front, ["0"]);
+    assert_eq!(back, ["1", "2", "3"]);
+    assert_eq!(q.iter().collect::<Vec<_>>(), ["0", "1", "2", "3"]);
+}
+
+#[test]
+fn force_push_evicts_from_the_other_end() {
+    let mut q = wrapped();
+    assert_eq!(q.force_push_back("4".to_string()).as_deref(), Some("0"));
+    assert_eq!(q.force_push_front("x".to_string()).as_deref(), Some("4"));
+    assert_eq!(q.iter().collect::<Vec<_>>(), ["x", "1", "2", "3"]);
+}
+
+#[test]
+fn into_iter_yields_elements_in_order() {
+    let mut iter = wrapped().into_iter();
+    assert_eq!(iter.next_back().as_deref(), Some("3"));
+    assert_eq!(iter.collect::<Vec<_>>(), ["0", "1", "2"]);
+}
+
+#[test]
+fn into_inner_requires_a_full_deque() {
+    assert_eq!(wrapped().into_inner().unwrap(), ["0", "1", "2", "3"]);
+
+    let mut q = wrapped();
+    q.pop_back();
+    let q = q.into_inner().unwrap_err();
+    assert_eq!(q.len(), 3);
+}
@@ -0,0 +1,72 @@
+//! Exercises `ArrayVec` on types that own heap memory.
+//!
+//! Drop counting under panics lives in `panic_safety.rs`; these check the values.
+
+use array_helpers::*;
+
+fn strings<const N: usize>() -> [String; N] {
+    new(|i| i.to_string())
+}
+
+#[test]
+fn push_pop_insert_remove() {
+    let mut v = ArrayVec::<String, 4>::new();
+    v.push("a".to_string());
+    v.push("c".to_string());
+    v.insert(1, "b".to_string());
+    assert_eq!(*v, ["a", "b", "c"]);
+    assert_eq!(v.remove(0), "a");
+    v.insert(2, "d".to_string());
+    assert_eq!(*v, ["b", "c", "d"]);
+    assert_eq!(v.pop().as_deref(), Some("d"));
+    assert_eq!(v.pop().as_deref(), Some("c"));
+    assert_eq!(v.pop().as_deref(), Some("b"));
+    assert_eq!(v.pop(), None);
+    assert!(v.is_empty());
+}
+
+#[test]
+fn extend_stops_at_capacity() {
+    let mut v = ArrayVec::<String, 4>::new();
+    v.extend(strings::<2>());
+    let mut rest = Array::into_iter(strings::<4>());
+    let err = v.try_extend(&mut rest).unwrap_err();
+    assert_eq!(err.into_inner(), "2");
+    assert_eq!(*v, ["0", "1", "0", "1"]);
+    assert_eq!(rest.as_slice(), ["3"]);
+    assert!(v.is_full());
+}
+
+#[test]
+fn drain_removes_the_range_and_shifts_the_tail() {
+    let mut v = ArrayVec::from(strings::<6>());
+    let drained: Vec<String> = v.drain(1..3).collect();
+    assert_eq!(drained, ["1", "2"]);
+    assert_eq!(*v, ["0", "3", "4", "5"]);
+
+    // Dropping a partly consumed `Drain` still removes the whole range.
+    assert_eq!(v.drain(..2).next_back().as_deref(), Some("3"));
+    assert_eq!(*v, ["4", "5"]);
+}
+
+#[test]
+fn truncate_and_into_inner() {
+    let mut v = ArrayVec::from(strings::<3>());
+    v.truncate(5);
+    assert_eq!(v.len(), 3);
+    v.truncate(2);
+    assert_eq!(*v, ["0", "1"]);
+
+    let mut v = v.into_inner().unwrap_err();
+    v.push("2".to_string());
+    assert_eq!(v.into_inner().unwrap(), ["0", "1", "2"]);
+}
+
+#[test]
+fn into_iter_yields_elements_in_order() {
+    let v = ArrayVec::from(strings::<4>());
+    let mut iter = v.into_iter();
+    assert_eq!(iter.next().as_deref(), Some("0"));
+    assert_eq!(iter.next_back().as_deref(), Some("3"));
+    assert_eq!(iter.collect::<Vec<_>>(), ["1", "2"]);
+}
@@ -0,0 +1,630 @@
+//! Checks that a panic or error partway through constructing or transforming an array
+//! drops every live element exactly once.
+
+use array_helpers::*;
+use std::cell::Cell;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+
+/// Increments a counter when created, and decrements it when dropped.
+///
+/// After everything is dropped, the counter is positive if something leaked,
+/// and negative if something was dropped twice.
+struct Token<'a>(&'a Cell<isize>);
+
+impl<'a> Token<'a> {
+    fn new(live: &'a Cell<isize>) -> Self {
+        live.set(live.get() + 1);
+        Token(live)
+    }
+}
+
+impl Clone for Token<'_> {
+    fn clone(&self) -> Self {
+        Token::new(self.0)
+    }
+}
+
+impl Drop for Token<'_> {
+    fn drop(&mut self) {
+        self.0.set(self.0.get() - 1);
+    }
+}
+
+/// A token whose destructor panics, if asked to.
+struct Bomb<'a> {
+    _token: Token<'a>,
+    armed: bool,
+}
+
+fn bomb(live: &Cell<isize>, armed: bool) -> Bomb<'_> {
+    Bomb { _token: Token::new(live), armed }
+}
+
+impl Drop for Bomb<'_> {
+    fn drop(&mut self) {
+        if self.armed {
+            panic!("boom");
+        }
+    }
+}
+
+fn panics<R>(f: impl FnOnce() -> R) -> bool {
+    catch_unwind(AssertUnwindSafe(f)).is_err()
+}
+
+#[test]
+fn new_drops_initialized_prefix() {
+    let live = Cell::new(0);
+    assert!(panics(|| {
+        new::<Token, 5>(|i| {
+            if i == 3 {
+                panic!();
+            }
+            Token::new(&live)
+        })
+    }));
+    assert_eq!(live.get(), 0);
+}
+
+#[test]
+fn try_new_drops_initialized_prefix() {
+    let live = Cell::new(0);
+    let arr: Result<[Token; 5], usize> = try_new(|i| if i == 3 { Err(i) } else { Ok(Token::new(&live)) });
+    assert_eq!(arr.err(), Some(3));
+    assert_eq!(live.get(), 0);
+
+    let arr: Option<[Token; 5]> = try_new_option(|i| if i == 4 { None } else { Some(Token::new(&live)) });
+    assert!(arr.is_none());
+    assert_eq!(live.get(), 0);
+}
+
+#[test]
+fn from_iter_keeps_collected_prefix() {
+    let live = Cell::new(0);
+    let arr: Result<[Token; 5], _> = from_iter((0..3).map(|_| Token::new(&live)));
+    assert_eq!(live.get(), 3);
+    drop(arr);
+    assert_eq!(live.get(), 0);
+
+    let arr: Result<[Token; 2], _> = from_iter((0..3).map(|_| Token::new(&live)));
+    match arr {
+        Err(FromIterError::TooMany(arr, rest)) => {
+            assert_eq!(live.get(), 3);
+            drop(arr);
+            assert_eq!(rest.count(), 1);
+        }
+        _ => unreachable!(),
+    }
+    assert_eq!(live.get(), 0);
+}
+
+#[test]
+fn map_drops_outputs_and_remaining_inputs() {
+    let inputs = Cell::new(0);
+    let outputs = Cell::new(0);
+    let arr: [Token; 5] = new(|_| Token::new(&inputs));
+    assert!(panics(|| {
+        Array::map(arr, |t| {
+            if inputs.get() == 2 {
+                panic!();
+            }
+            drop(t);
+            Token::new(&outputs)
+        })
+    }));
+    assert_eq!(inputs.get(), 0);
+    assert_eq!(outputs.get(), 0);
+}
+
+#[test]
+fn map_indexed_drops_outputs_and_remaining_inputs() {
+    let live = Cell::new(0);
+    let arr: [Token; 5] = new(|_| Token::new(&live));
+    assert!(panics(|| {
+        arr.map_indexed(|i, t| {
+            if i == 3 {
+                panic!();
+            }
+            t
+        })
+    }));
+    assert_eq!(live.get(), 0);
+}
+
+#[test]
+fn zip_with_drops_outputs_and_remaining_inputs() {
+    let live = Cell::new(0);
+    let arr1: [Token; 4] = new(|_| Token::new(&live));
+    let arr2: [Token; 4] = new(|_| Token::new(&live));
+    let mut calls = 0;
+    assert!(panics(|| {
+        arr1.zip_with(arr2, |a, b| {
+            calls += 1;
+            if calls == 3 {
+                panic!();
+            }
+            (a, b)
+        })
+    }));
+    assert_eq!(live.get(), 0);
+}
+
+#[test]
+fn try_map_drops_outputs_and_remaining_inputs() {
+    let inputs = Cell::new(0);
+    let outputs = Cell::new(0);
+    let arr: [Token; 5] = new(|_| Token::new(&inputs));
+    let res = Array::try_map(arr, |t| {
+        if inputs.get() == 2 {
+            return Err(());
+        }
+        drop(t);
+        Ok(Token::new(&outputs))
+    });
+    assert!(res.is_err());
+    assert_eq!(inputs.get(), 0);
+    assert_eq!(outputs.get(), 0);
+}
+
+#[test]
+fn try_zip_with_drops_outputs_and_remaining_inputs() {
+    let live = Cell::new(0);
+    let calls = Cell::new(0);
+    let arr1: [Token; 4] = new(|_| Token::new(&live));
+    let arr2: [Token; 4] = new(|_| Token::new(&live));
+    let res = arr1.try_zip_with(arr2, |a, b| {
+        calls.set(calls.get() + 1);
+        if calls.get() == 3 {
+            return Err(());
+        }
+        Ok((a, b))
+    });
+    assert!(res.is_err());
+    assert_eq!(live.get(), 0);
+}
+
+#[test]
+fn zip_moves_each_element_once() {
+    let live = Cell::new(0);
+    let arr1: [Token; 4] = new(|_| Token::new(&live));
+    let arr2: [Token; 4] = new(|_| Token::new(&live));
+    let zipped = arr1.zip(arr2);
+    assert_eq!(live.get(), 8);
+    drop(zipped);
+    assert_eq!(live.get(), 0);
+}
+
+#[test]
+fn tuple_zip_and_unzip_move_each_element_once() {
+    let live = Cell::new(0);
+    let arrs: ([Token; 3], [Token; 3], [Token; 3]) = (
+        new(|_| Token::new(&live)),
+        new(|_| Token::new(&live)),
+        new(|_| Token::new(&live)),
+    );
+    let zipped = arrs.zip_all();
+    assert_eq!(live.get(), 9);
+    let (a, b, c) = zipped.unzip();
+    assert_eq!(live.get(), 9);
+    drop((a, b));
+    assert_eq!(live.get(), 3);
+    drop(c);
+    assert_eq!(live.get(), 0);
+}
+
+#[test]
+fn transformations_do_not_double_drop() {
+    let live = Cell::new(0);
+    let arr: [[Token; 3]; 2] = new(|_| new(|_| Token::new(&live)));
+    let arr = arr.transpose();
+    assert_eq!(live.get(), 6);
+    let (a, b) = Array::map(arr, |[x, y]| (x, y)).unzip();
+    assert_eq!(live.get(), 6);
+    drop(a);
+    assert_eq!(live.get(), 3);
+    drop(b);
+    assert_eq!(live.get(), 0);
+}
+
+#[test]
+fn nested_map_drops_remaining_rows() {
+    let live = Cell::new(0);
+    let calls = Cell::new(0);
+    let arr: [[Token; 3]; 3] = new(|_| new(|_| Token::new(&live)));
+    assert!(panics(|| {
+        Array::map(arr.transpose(), |row| {
+            Array::map(row, |t| {
+                calls.set(calls.get() + 1);
+                if calls.get() == 5 {
+                    panic!();
+                }
+                t
+            })
+        })
+    }));
+    assert_eq!(live.get(), 0);
+}
+
+#[test]
+fn unzip_moves_each_element_once() {
+    let live = Cell::new(0);
+    let arr: [(Token, Token); 3] = new(|_| (Token::new(&live), Token::new(&live)));
+    let (a, b) = arr.unzip();
+    assert_eq!(live.get(), 6);
+    drop((a, b));
+    assert_eq!(live.get(), 0);
+}
+
+#[test]
+fn into_iter_skips_and_drops_from_both_ends() {
+    let live = Cell::new(0);
+    let arr: [Token; 8] = new(|_| Token::new(&live));
+    let mut iter = Array::into_iter(arr);
+    assert!(iter.nth(2).is_some());
+    assert_eq!(live.get(), 5);
+    assert!(iter.nth_back(1).is_some());
+    assert_eq!(live.get(), 3);
+    assert_eq!(iter.len(), 3);
+    let clone = iter.clone();
+    assert_eq!(live.get(), 6);
+    drop(iter);
+    assert_eq!(clone.rfold(0, |n, _| n + 1), 3);
+    assert_eq!(live.get(), 0);
+}
+
+#[test]
+fn into_iter_hands_back_remaining_elements() {
+    let live = Cell::new(0);
+    let arr: [Token; 6] = new(|_| Token::new(&live));
+    let mut iter = Array::into_iter(arr);
+    iter.next();
+    iter.next_back();
+    let iter = iter.try_into_array().err().unwrap();
+    assert_eq!(live.get(), 4);
+    let rest = iter.into_array_vec();
+    assert_eq!(rest.len(), 4);
+    assert_eq!(live.get(), 4);
+    drop(rest);
+    assert_eq!(live.get(), 0);
+
+    let arr: [Token; 3] = new(|_| Token::new(&live));
+    let arr = Array::into_iter(arr).try_into_array().ok().unwrap();
+    assert_eq!(live.get(), 3);
+    drop(arr);
+    assert_eq!(live.get(), 0);
+}
+
+#[test]
+fn into_iter_drops_everything_despite_panicking_destructor() {
+    let live = Cell::new(0);
+    let arr: [Bomb; 6] = new(|i| bomb(&live, i == 3));
+    let mut iter = Array::into_iter(arr);
+    drop(iter.next());
+    assert_eq!(live.get(), 5);
+    assert!(panics(|| drop(iter)));
+    assert_eq!(live.get(), 0);
+}
+
+#[test]
+fn into_iter_nth_drops_everything_despite_panicking_destructor() {
+    let live = Cell::new(0);
+    let arr: [Bomb; 6] = new(|i| bomb(&live, i == 1));
+    let mut iter = Array::into_iter(arr);
+    assert!(panics(|| iter.nth(3)));
+    assert_eq!(live.get(), 3);
+    assert_eq!(iter.len(), 3);
+    drop(iter);
+    assert_eq!(live.get(), 0);
+}
+
+#[test]
+fn try_map_drops_everything_despite_panicking_destructor() {
+    let live = Cell::new(0);
+    let arr: [Bomb; 5] = new(|i| bomb(&live, i == 3));
+    assert!(panics(|| Array::try_map(arr, |_| Err::<(), ()>(()))));
+    assert_eq!(live.get(), 0);
+}
+
+#[test]
+fn resizing_moves_each_element_once() {
+    let live = Cell::new(0);
+    let arr: [Token; 3] = new(|_| Token::new(&live));
+    let arr: [Token; 5] = arr.concat(new::<Token, 2>(|_| Token::new(&live)));
+    let arr: [Token; 6] = arr.insert::<2, 6>(Token::new(&live));
+    assert_eq!(live.get(), 6);
+    let (arr, removed): ([Token; 5], Token) = arr.remove::<4, 5>();
+    drop(removed);
+    assert_eq!(live.get(), 5);
+    let (first, rest): (Token, [Token; 4]) = arr.split_first();
+    drop(first);
+    let (left, right) = rest.split_at::<1, 3>();
+    drop(left);
+    assert_eq!(live.get(), 3);
+    drop(right);
+    assert_eq!(live.get(), 0);
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn boxed_map_in_place_drops_outputs_and_remaining_inputs() {
+    let inputs = Cell::new(0);
+    let outputs = Cell::new(0);
+    let arr: Box<[Token; 5]> = Box::new(new(|_| Token::new(&inputs)));
+    assert!(panics(|| {
+        BoxedArray::map(arr, |t| {
+            if inputs.get() == 2 {
+                panic!();
+            }
+            drop(t);
+            Token::new(&outputs)
+        })
+    }));
+    assert_eq!(inputs.get(), 0);
+    assert_eq!(outputs.get(), 0);
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn boxed_map_to_other_layout_drops_outputs_and_remaining_inputs() {
+    let live = Cell::new(0);
+    let arr: Box<[Token; 5]> = Box::new(new(|_| Token::new(&live)));
+    let mut calls = 0;
+    assert!(panics(|| {
+        BoxedArray::map(arr, |t| {
+            calls += 1;
+            if calls == 3 {
+                panic!();
+            }
+            (t, 0u64)
+        })
+    }));
+    assert_eq!(live.get(), 0);
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn boxed_transpose_moves_each_element_once() {
+    let live = Cell::new(0);
+    let arr: Box<[[(usize, Token); 5]; 3]> = Box::new(new(|i| new(|j| (5 * i + j, Token::new(&live)))));
+    let arr = arr.transpose();
+    assert_eq!(live.get(), 15);
+    for (i, row) in arr.iter().enumerate() {
+        for (j, (n, _)) in row.iter().enumerate() {
+            assert_eq!(*n, 5 * j + i);
+        }
+    }
+    let (a, b) = BoxedArray::map(arr, |[a, b, _]| (a, b)).unzip();
+    assert_eq!(live.get(), 10);
+    drop((a, b));
+    assert_eq!(live.get(), 0);
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn new_boxed_drops_initialized_prefix() {
+    let live = Cell::new(0);
+    assert!(panics(|| {
+        new_boxed::<Token, 5>(|i| {
+            if i == 3 {
+                panic!();
+            }
+            Token::new(&live)
+        })
+    }));
+    assert_eq!(live.get(), 0);
+
+    let arr: Result<Box<[Token; 5]>, _> = try_new_boxed(|i| if i == 3 { Err(i) } else { Ok(Token::new(&live)) });
+    assert!(matches!(arr, Err(NewBoxedError::Closure(3))));
+    assert_eq!(live.get(), 0);
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn new_rc_drops_initialized_prefix() {
+    let live = Cell::new(0);
+    assert!(panics(|| {
+        new_rc::<Token, 5>(|i| {
+            if i == 3 {
+                panic!();
+            }
+            Token::new(&live)
+        })
+    }));
+    assert_eq!(live.get(), 0);
+
+    let arr: Result<std::rc::Rc<[Token; 5]>, _> = try_new_rc(|i| if i == 3 { Err(i) } else { Ok(Token::new(&live)) });
+    assert!(matches!(arr, Err(3)));
+    assert_eq!(live.get(), 0);
+
+    let arr: std::rc::Rc<[Token; 5]> = new_rc(|_| Token::new(&live));
+    assert_eq!(live.get(), 5);
+    drop(arr);
+    assert_eq!(live.get(), 0);
+}
+
+#[test]
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+fn new_arc_drops_initialized_prefix() {
+    use std::sync::atomic::{AtomicIsize, Ordering};
+    static LIVE: AtomicIsize = AtomicIsize::new(0);
+    struct Counted;
+    impl Drop for Counted {
+        fn drop(&mut self) {
+            LIVE.fetch_sub(1, Ordering::SeqCst);
+        }
+    }
+    let counted = || {
+        LIVE.fetch_add(1, Ordering::SeqCst);
+        Counted
+    };
+
+    assert!(panics(|| {
+        new_arc::<Counted, 5>(|i| {
+            if i == 3 {
+                panic!();
+            }
+            counted()
+        })
+    }));
+    assert_eq!(LIVE.load(Ordering::SeqCst), 0);
+
+    let arr: Result<std::sync::Arc<[Counted; 5]>, _> = try_new_arc(|i| if i == 3 { Err(i) } else { Ok(counted()) });
+    assert!(matches!(arr, Err(3)));
+    assert_eq!(LIVE.load(Ordering::SeqCst), 0);
+
+    let arr: std::sync::Arc<[Counted; 5]> = new_arc(|_| counted());
+    assert_eq!(LIVE.load(Ordering::SeqCst), 5);
+    drop(arr);
+    assert_eq!(LIVE.load(Ordering::SeqCst), 0);
+}
+
+#[test]
+fn init_in_place_drops_initialized_prefix() {
+    use std::mem::MaybeUninit;
+    let live = Cell::new(0);
+    let mut slot = MaybeUninit::<[Token; 5]>::uninit();
+    assert!(panics(|| {
+        init_in_place(&mut slot, |i| {
+            if i == 3 {
+                panic!();
+            }
+            Token::new(&live)
+        });
+    }));
+    assert_eq!(live.get(), 0);
+
+    assert!(matches!(try_init_in_place(&mut slot, |i| if i == 3 { Err(i) } else { Ok(Token::new(&live)) }), Err(3)));
+    assert_eq!(live.get(), 0);
+
+    init_in_place(&mut slot, |_| Token::new(&live));
+    assert_eq!(live.get(), 5);
+    unsafe { slot.assume_init_drop() };
+    assert_eq!(live.get(), 0);
+}
+
+#[test]
+fn array_vec_drain_moves_tail_despite_panicking_destructor() {
+    let live = Cell::new(0);
+    let mut v = ArrayVec::<Bomb, 5>::new();
+    for i in 0..5 {
+        v.push(bomb(&live, i == 1));
+    }
+    assert!(panics(|| drop(v.drain(0..3))));
+    assert_eq!(v.len(), 2);
+    assert_eq!(live.get(), 2);
+    drop(v);
+    assert_eq!(live.get(), 0);
+}
+
+#[test]
+fn array_vec_clone_drops_clones_on_panic() {
+    let live = Cell::new(0);
+    let v: ArrayVec<Token, 4> = (0..4).map(|_| Token::new(&live)).collect();
+    let calls = Cell::new(0);
+    struct Flaky<'a>(Token<'a>, &'a Cell<usize>);
+    impl Clone for Flaky<'_> {
+        fn clone(&self) -> Self {
+            self.1.set(self.1.get() + 1);
+            if self.1.get() == 3 {
+                panic!();
+            }
+            Flaky(self.0.clone(), self.1)
+        }
+    }
+    let v: ArrayVec<Flaky, 4> = v.into_iter().map(|t| Flaky(t, &calls)).collect();
+    assert!(panics(|| v.clone()));
+    assert_eq!(live.get(), 4);
+    drop(v);
+    assert_eq!(live.get(), 0);
+}
+
+#[test]
+fn filtering_drops_kept_and_remaining_elements_on_panic() {
+    let live = Cell::new(0);
+    let arr: [Token; 6] = new(|_| Token::new(&live));
+    let mut calls = 0;
+    assert!(panics(|| {
+        arr.partition(|_| {
+            calls += 1;
+            if calls == 4 {
+                panic!();
+            }
+            calls % 2 == 0
+        })
+    }));
+    assert_eq!(live.get(), 0);
+
+    let arr: [Token; 6] = new(|_| Token::new(&live));
+    let mut calls = 0;
+    let kept = arr.dedup_by_key(|_| {
+        calls += 1;
+        calls / 2
+    });
+    assert_eq!(kept.len(), 4);
+    assert_eq!(live.get(), 4);
+    drop(kept);
+    assert_eq!(live.get(), 0);
+}
+
+#[test]
+fn array_deque_drop_continues_past_panicking_destructor() {
+    let live = Cell::new(0);
+    let mut q = ArrayDeque::<Bomb, 4>::new();
+    for _ in 0..3 {
+        q.push_back(bomb(&live, false));
+    }
+    q.push_front(bomb(&live, true));
+    drop(q.pop_back());
+    q.push_back(bomb(&live, false));
+    // The elements now wrap around the end of the buffer, and the first slice holds the armed bomb.
+    assert!(panics(|| drop(q)));
+    assert_eq!(live.get(), 0);
+}
+
+#[test]
+fn transposing_options_and_results_drops_the_successes() {
+    let live = Cell::new(0);
+    let arr: [Option<Token>; 4] = new(|i| if i == 2 { None } else { Some(Token::new(&live)) });
+    assert!(arr.transpose_option().is_none());
+    assert_eq!(live.get(), 0);
+
+    let arr: [Result<Token, Token>; 4] = new(|i| if i % 2 == 1 { Err(Token::new(&live)) } else { Ok(Token::new(&live)) });
+    let err = arr.transpose_result().err().unwrap();
+    assert_eq!(live.get(), 1);
+    drop(err);
+    assert_eq!(live.get(), 0);
+
+    let arr = Some(new::<Token, 3>(|_| Token::new(&live))).transpose_array();
+    assert_eq!(live.get(), 3);
+    let arr = arr.transpose_option().transpose_array();
+    assert_eq!(live.get(), 3);
+    drop(arr);
+    assert_eq!(live.get(), 0);
+}
+
+#[test]
+fn new_with_prefix_and_unfold_drop_initialized_prefix() {
+    let live = Cell::new(0);
+    assert!(panics(|| {
+        new_with_prefix::<Token, 5>(|i, prefix| {
+            assert_eq!(prefix.len(), i);
+            if i == 3 {
+                panic!();
+            }
+            Token::new(&live)
+        })
+    }));
+    assert_eq!(live.get(), 0);
+
+    let state = Token::new(&live);
+    assert!(panics(|| {
+        unfold::<Token, _, 5>((state, 0), |(token, calls)| {
+            *calls += 1;
+            if *calls == 4 {
+                panic!();
+            }
+            token.clone()
+        })
+    }));
+    assert_eq!(live.get(), 0);
+}
@@ -0,0 +1,51 @@
+//! Exercises the `raw` module on types that own heap memory.
+//!
+//! CI runs these under Miri (`cargo +nightly miri test --test raw`),
+//! which reports any read of uninitialized memory, double free, or leak.
+
+use array_helpers::raw::*;
+use std::mem::MaybeUninit;
+
+#[test]
+fn uninit_array_then_assume_init_array() {
+    let mut arr = uninit_array::<String, 3>();
+    for (i, slot) in arr.iter_mut().enumerate() {
+        *slot = MaybeUninit::new(i.to_string());
+    }
+    let arr = unsafe { assume_init_array(arr) };
+    assert_eq!(arr, ["0", "1", "2"]);
+}
+
+#[test]
+fn push_then_pull_round_trips() {
+    let arr = push_maybe_uninit(MaybeUninit::new([vec![1], vec![2, 3]]));
+    let arr = pull_maybe_uninit(arr);
+    assert_eq!(unsafe { arr.assume_init() }, [vec![1], vec![2, 3]]);
+
+    let arr: [MaybeUninit<Box<u8>>; 0] = push_maybe_uninit(MaybeUninit::uninit());
+    assert_eq!(unsafe { assume_init_array(arr) }, [] as [Box<u8>; 0]);
+}
+
+#[test]
+fn extract_leaves_a_hole_that_can_be_refilled() {
+    let mut arr = [MaybeUninit::new(Box::new(1)), MaybeUninit::new(Box::new(2))];
+    let first = unsafe { extract(&mut arr, 0) };
+    assert_eq!(*first, 1);
+    arr[0] = MaybeUninit::new(Box::new(3));
+    let arr = unsafe { assume_init_array(arr) };
+    assert_eq!(arr, [Box::new(3), Box::new(2)]);
+}
+
+#[test]
+fn prefix_views_see_only_the_initialized_elements() {
+    let mut arr = uninit_array::<String, 4>();
+    arr[0] = MaybeUninit::new("a".to_string());
+    arr[1] = MaybeUninit::new("b".to_string());
+
+    let prefix = unsafe { assume_init_mut(&mut arr[..2]) };
+    prefix[1].push('!');
+    assert_eq!(unsafe { assume_init_ref(&arr[..2]) }, ["a", "b!"]);
+    assert_eq!(unsafe { assume_init_ref(&arr[..0]) }, [] as [String; 0]);
+
+    unsafe { std::ptr::drop_in_place(assume_init_mut(&mut arr[..2])) };
+}